@@ -10,8 +10,10 @@
 //! - Generation of a prover ID.
 //! - Generation of a Score.
 //! - Generation of a Proof of BlindBid.
+pub(crate) mod builder;
 pub(crate) mod encoding;
 pub(crate) mod score;
+pub(crate) mod tier;
 use crate::errors::BlindBidError;
 
 #[cfg(feature = "canon")]
@@ -19,17 +21,86 @@ use canonical::Canon;
 #[cfg(feature = "canon")]
 use canonical_derive::Canon;
 
+use crate::hasher::{BidHasher, DefaultHasher};
+pub use builder::BidBuilder;
 use core::borrow::Borrow;
+use core::convert::TryFrom;
+use core::hash::{Hash, Hasher};
 use dusk_bls12_381::BlsScalar;
 use dusk_bytes::{DeserializableSlice, Serializable};
 use dusk_jubjub::{
-    JubJubAffine, JubJubScalar, GENERATOR_EXTENDED, GENERATOR_NUMS_EXTENDED,
+    JubJubAffine, JubJubExtended, JubJubScalar, GENERATOR_EXTENDED,
+    GENERATOR_NUMS_EXTENDED,
 };
 use dusk_pki::{Ownable, StealthAddress};
+#[cfg(feature = "std")]
+use dusk_pki::{PublicSpendKey, SecretSpendKey};
 use dusk_poseidon::cipher::PoseidonCipher;
-use dusk_poseidon::sponge;
 use rand_core::{CryptoRng, RngCore};
-pub use score::Score;
+#[cfg(feature = "debug-trace")]
+pub use score::ScoreTrace;
+#[cfg(feature = "std")]
+pub use score::{DefaultScoreFunction, ScoreFunction, ScorePreimage};
+pub use score::{Score, ScoreContext, SCORE_SERIALIZED_SIZE};
+use subtle::{Choice, ConstantTimeEq};
+pub use tier::BidTier;
+#[cfg(feature = "zeroize")]
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Version tag written by [`Bid::to_bytes_versioned`].
+///
+/// Version `0` identifies the layout without the `metadata` field, see
+/// [`BID_LEGACY_SERIALIZED_SIZE`].
+pub const BID_FORMAT_VERSION: u8 = 1;
+
+/// Length of the [Serializable] representation of a [`Bid`].
+///
+/// Equal to `Bid::SIZE`, but usable in generic contexts without naming the
+/// `Serializable` impl.
+pub const BID_SERIALIZED_SIZE: usize = Bid::SIZE;
+
+/// JSON returned by [`Bid::wire_schema`].
+const BID_WIRE_SCHEMA: &str = r#"{
+  "name": "Bid",
+  "size": 312,
+  "fields": [
+    {"name": "encrypted_data", "type": "PoseidonCipher", "offset": 0, "size": 96, "encoding": "3 little-endian BLS12-381 scalars"},
+    {"name": "nonce", "type": "BlsScalar", "offset": 96, "size": 32, "encoding": "little-endian BLS12-381 scalar"},
+    {"name": "stealth_address", "type": "StealthAddress", "offset": 128, "size": 64, "encoding": "2 compressed JubJub points (R, pk_r)"},
+    {"name": "hashed_secret", "type": "BlsScalar", "offset": 192, "size": 32, "encoding": "little-endian BLS12-381 scalar"},
+    {"name": "commitment", "type": "JubJubAffine", "offset": 224, "size": 32, "encoding": "compressed JubJub point"},
+    {"name": "eligibility", "type": "u64", "offset": 256, "size": 8, "encoding": "little-endian"},
+    {"name": "expiration", "type": "u64", "offset": 264, "size": 8, "encoding": "little-endian"},
+    {"name": "pos", "type": "u64", "offset": 272, "size": 8, "encoding": "little-endian"},
+    {"name": "metadata", "type": "[u8; 32]", "offset": 280, "size": 32, "encoding": "raw bytes"}
+  ]
+}"#;
+
+/// Length of the representation of a [`Bid`] used before the `metadata`
+/// field was introduced, which [`Bid::from_legacy_bytes`] decodes.
+///
+/// The legacy layout is the current one without its last 32 bytes.
+pub const BID_LEGACY_SERIALIZED_SIZE: usize = Bid::SIZE - 32;
+
+/// The [Serializable] representation of a [`Bid`], as returned by
+/// `Bid::to_bytes`.
+pub type BidBytes = [u8; BID_SERIALIZED_SIZE];
+
+/// Domain separator prepended to the preimage of the hashed secret of a Bid,
+/// see [`hash_secret_k`]. It is the ASCII string `secret` read as a
+/// little-endian integer.
+pub const DOMAIN_HASHED_SECRET: BlsScalar =
+    BlsScalar::from_raw([0x746572636573, 0, 0, 0]);
+
+/// Domain separator prepended to the preimage of the prover_id, see
+/// [`compute_prover_id`]. It is the ASCII string `proverid` read as a
+/// little-endian integer.
+pub const DOMAIN_PROVER_ID: BlsScalar =
+    BlsScalar::from_raw([0x64697265766f7270, 0, 0, 0]);
+
+/// Domain separator used to derive the nonce of a Bid, see
+/// [`Bid::derive_nonce`].
+const DOMAIN_NONCE: BlsScalar = BlsScalar::from_raw([0x65636e6f6e, 0, 0, 0]);
 
 /// The Bid structure contains all of the logic and information needed to be
 /// able to participate in the Dusk consensus lottery through the bidding
@@ -109,17 +180,82 @@ pub struct Bid {
     /// Stealth address of the bidder.
     stealth_address: StealthAddress,
     /// Hashed secret
-    pub(crate) hashed_secret: BlsScalar,
+    hashed_secret: BlsScalar,
     /// Commitment containing value & blinder fields hidden.
-    pub(crate) c: JubJubAffine,
+    c: JubJubAffine,
     /// Elegibility height
     pub(crate) eligibility: u64,
     /// Expiration height
     pub(crate) expiration: u64,
     /// Position of the Bid in the Tree where it is stored.
     pub(crate) pos: u64,
+    /// Opaque application data attached to the Bid. Not part of the
+    /// consensus.
+    metadata: [u8; 32],
+}
+
+/// Data stored encrypted inside of a [Bid]: the value at stake and the
+/// blinder used to generate the Bid commitment.
+///
+/// With the `zeroize` feature enabled, both fields are wiped when the
+/// structure is dropped. The structure is never `Copy`, regardless of the
+/// features, so enabling `zeroize` doesn't change its API.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecryptedBid {
+    value: JubJubScalar,
+    blinder: JubJubScalar,
 }
 
+impl DecryptedBid {
+    /// Returns the value at stake in the Bid.
+    pub fn value(&self) -> JubJubScalar {
+        self.value
+    }
+
+    /// Returns the blinder of the Bid commitment.
+    pub fn blinder(&self) -> JubJubScalar {
+        self.blinder
+    }
+
+    /// Returns the value at stake in the Bid as the `u64` it was created
+    /// from, or `None` if it doesn't fit in one.
+    ///
+    /// Values in the `[V_RAW_MIN, V_RAW_MAX]` range always fit.
+    pub fn value_u64(&self) -> Option<u64> {
+        let bytes = self.value.to_bytes();
+        if bytes[8..].iter().any(|b| *b != 0) {
+            return None;
+        }
+        let mut low = [0u8; 8];
+        low.copy_from_slice(&bytes[..8]);
+        Some(u64::from_le_bytes(low))
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Zeroize for DecryptedBid {
+    fn zeroize(&mut self) {
+        // Volatile writes so the wiping isn't optimized away.
+        unsafe {
+            core::ptr::write_volatile(&mut self.value, JubJubScalar::zero());
+            core::ptr::write_volatile(&mut self.blinder, JubJubScalar::zero());
+        }
+        core::sync::atomic::compiler_fence(
+            core::sync::atomic::Ordering::SeqCst,
+        );
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for DecryptedBid {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl ZeroizeOnDrop for DecryptedBid {}
+
 impl Borrow<u64> for Bid {
     fn borrow(&self) -> &u64 {
         &self.pos
@@ -133,13 +269,48 @@ impl Ownable for Bid {
 }
 
 impl PartialEq for Bid {
+    /// Compares the Bids field by field, in constant time for the scalar and
+    /// point fields.
     fn eq(&self, other: &Self) -> bool {
-        self.hash().eq(&other.hash())
+        let cipher_eq = self
+            .encrypted_data
+            .cipher()
+            .iter()
+            .zip(other.encrypted_data.cipher().iter())
+            .fold(Choice::from(1u8), |acc, (a, b)| acc & a.ct_eq(b));
+
+        let ct_eq = cipher_eq
+            & self.nonce.ct_eq(&other.nonce)
+            & self.stealth_address.R().ct_eq(other.stealth_address.R())
+            & self
+                .stealth_address
+                .pk_r()
+                .as_ref()
+                .ct_eq(other.stealth_address.pk_r().as_ref())
+            & self.hashed_secret.ct_eq(&other.hashed_secret)
+            & self.c.ct_eq(&other.c)
+            & self.metadata[..].ct_eq(&other.metadata[..]);
+
+        bool::from(ct_eq)
+            && self.eligibility == other.eligibility
+            && self.expiration == other.expiration
+            && self.pos == other.pos
     }
 }
 
 impl Eq for Bid {}
 
+impl Hash for Bid {
+    /// Feeds the bytes of [`Bid::hash`] into `state`, so Bids that are equal
+    /// also hash identically and can be used as `HashMap` keys.
+    ///
+    /// This computes a Poseidon hash on every call, so callers hashing the
+    /// same Bid many times are better off keying their maps by its hash.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Bid::hash(self).to_bytes().hash(state);
+    }
+}
+
 // This needs to be between braces since const fn calls passed as const_generics
 // params aren't perfectly supported yet.
 impl
@@ -150,6 +321,7 @@ impl
                 + 2 * BlsScalar::SIZE
                 + JubJubAffine::SIZE
                 + 8 * 3
+                + 32
         },
     > for Bid
 {
@@ -165,6 +337,8 @@ impl
         let eligibility = u64::from_reader(&mut buffer)?;
         let expiration = u64::from_reader(&mut buffer)?;
         let pos = u64::from_reader(&mut buffer)?;
+        let mut metadata = [0u8; 32];
+        metadata.copy_from_slice(&buffer[..32]);
 
         Ok(Bid {
             encrypted_data,
@@ -175,15 +349,74 @@ impl
             eligibility,
             expiration,
             pos,
+            metadata,
         })
     }
 
+    fn to_bytes(&self) -> BidBytes {
+        let mut buf = [0u8; Self::SIZE];
+        // The buffer has exactly the required length, so this can't fail.
+        let _ = self.to_bytes_into(&mut buf);
+        buf
+    }
+}
+
+impl TryFrom<&[u8]> for Bid {
+    type Error = BlindBidError;
+
+    /// Deserializes a Bid from a slice of exactly
+    /// [`Bid::SIZE`](Serializable::SIZE) bytes, or of
+    /// [`BID_LEGACY_SERIALIZED_SIZE`] bytes for the layout without metadata.
+    fn try_from(bytes: &[u8]) -> Result<Bid, BlindBidError> {
+        if bytes.len() == BID_LEGACY_SERIALIZED_SIZE {
+            let mut legacy = [0u8; BID_LEGACY_SERIALIZED_SIZE];
+            legacy.copy_from_slice(bytes);
+            return Bid::from_legacy_bytes(&legacy);
+        }
+        if bytes.len() != Bid::SIZE {
+            return Err(BlindBidError::SerializationError(
+                dusk_bytes::Error::BadLength {
+                    found: bytes.len(),
+                    expected: Bid::SIZE,
+                },
+            ));
+        }
+        Ok(Bid::from_slice(bytes)?)
+    }
+}
+
+impl Bid {
+    /// Writes the [Serializable] representation of the Bid into the first
+    /// [`Bid::SIZE`](Serializable::SIZE) bytes of `buf`.
+    ///
+    /// The layout of the representation is:
+    /// - `0..96`: the encrypted value & blinder.
+    /// - `96..128`: the nonce.
+    /// - `128..192`: the stealth address.
+    /// - `192..224`: the hashed secret.
+    /// - `224..256`: the commitment.
+    /// - `256..264`: the eligibility, as a little-endian `u64`.
+    /// - `264..272`: the expiration, as a little-endian `u64`.
+    /// - `272..280`: the position, as a little-endian `u64`.
+    /// - `280..312`: the metadata.
+    ///
+    /// Fails with [`dusk_bytes::Error::BadLength`] if `buf` is shorter than
+    /// that.
     #[allow(unused_must_use)]
-    fn to_bytes(&self) -> [u8; Self::SIZE] {
+    pub fn to_bytes_into(
+        &self,
+        buf: &mut [u8],
+    ) -> Result<(), dusk_bytes::Error> {
         use dusk_bytes::Write;
 
-        let mut buf = [0u8; Self::SIZE];
-        let mut writer = &mut buf[..];
+        if buf.len() < Self::SIZE {
+            return Err(dusk_bytes::Error::BadLength {
+                found: buf.len(),
+                expected: Self::SIZE,
+            });
+        }
+
+        let mut writer = &mut buf[..Self::SIZE];
         writer.write(&self.encrypted_data.to_bytes());
         writer.write(&self.nonce.to_bytes());
         writer.write(&self.stealth_address.to_bytes());
@@ -192,12 +425,84 @@ impl
         writer.write(&self.eligibility.to_bytes());
         writer.write(&self.expiration.to_bytes());
         writer.write(&self.pos.to_bytes());
+        writer.write(&self.metadata);
+        Ok(())
+    }
+
+    /// Deserializes a Bid encoded with the layout used before the `metadata`
+    /// field was introduced, which is the current one without the metadata.
+    ///
+    /// The metadata of the returned Bid is zeroed. Since it isn't part of
+    /// the hash of the Bid, the hash is the same one the Bid had before.
+    pub fn from_legacy_bytes(
+        bytes: &[u8; BID_LEGACY_SERIALIZED_SIZE],
+    ) -> Result<Bid, BlindBidError> {
+        let mut buf = [0u8; Bid::SIZE];
+        buf[..BID_LEGACY_SERIALIZED_SIZE].copy_from_slice(bytes);
+        Ok(Bid::from_bytes(&buf)?)
+    }
+
+    /// Returns a JSON description of the [Serializable] representation of
+    /// the Bid, meant for implementations in other languages.
+    ///
+    /// Every field is described by its `name`, `type`, `offset`, `size` and
+    /// `encoding`, in the order of the layout documented in
+    /// [`Bid::to_bytes_into`]. This string is stable as long as the layout
+    /// is.
+    pub fn wire_schema() -> &'static str {
+        BID_WIRE_SCHEMA
+    }
+
+    /// Serializes the Bid prefixed with a one-byte version tag, currently
+    /// [`BID_FORMAT_VERSION`].
+    ///
+    /// Version `1` is followed by the fixed [Serializable] layout of the Bid,
+    /// which is kept untouched. Future versions can append fields to it,
+    /// while readers that don't know them reject the Bid instead of
+    /// misreading it.
+    pub fn to_bytes_versioned(&self) -> [u8; Bid::SIZE + 1] {
+        let mut buf = [0u8; Bid::SIZE + 1];
+        buf[0] = BID_FORMAT_VERSION;
+        // The buffer has exactly the required length, so this can't fail.
+        let _ = self.to_bytes_into(&mut buf[1..]);
         buf
     }
-}
 
-impl Bid {
+    /// Deserializes a Bid generated with [`Bid::to_bytes_versioned`].
+    ///
+    /// Version `0` is followed by the layout without the `metadata` field,
+    /// decoded with [`Bid::from_legacy_bytes`].
+    ///
+    /// Fails with [`BlindBidError::UnsupportedVersion`] if the version tag
+    /// is not a known one.
+    pub fn from_bytes_versioned(bytes: &[u8]) -> Result<Bid, BlindBidError> {
+        let (version, bytes) =
+            bytes.split_first().ok_or(dusk_bytes::Error::BadLength {
+                found: 0,
+                expected: Bid::SIZE + 1,
+            })?;
+        match *version {
+            0 => {
+                if bytes.len() != BID_LEGACY_SERIALIZED_SIZE {
+                    return Err(BlindBidError::SerializationError(
+                        dusk_bytes::Error::BadLength {
+                            found: bytes.len(),
+                            expected: BID_LEGACY_SERIALIZED_SIZE,
+                        },
+                    ));
+                }
+                let mut legacy = [0u8; BID_LEGACY_SERIALIZED_SIZE];
+                legacy.copy_from_slice(bytes);
+                Bid::from_legacy_bytes(&legacy)
+            }
+            BID_FORMAT_VERSION => Ok(Bid::from_slice(bytes)?),
+            version => Err(BlindBidError::UnsupportedVersion(version)),
+        }
+    }
+
     /// Generates a new Bid from a rng source plus it's fields.  
+    ///
+    /// This is a shorthand for building the Bid with a [BidBuilder].
     pub fn new<R>(
         rng: &mut R,
         stealth_address: &StealthAddress,
@@ -210,50 +515,265 @@ impl Bid {
     where
         R: RngCore + CryptoRng,
     {
-        // Check if the bid_value is in the correct range, otherways, fail.
-        match (
-            value.reduce() > crate::V_MAX.reduce(),
-            value.reduce() < crate::V_MIN.reduce(),
-        ) {
-            (true, false) => {
-                return Err(BlindBidError::MaximumBidValueExceeded {
-                    max_val: crate::V_MAX,
-                    found: *value,
-                })?;
-            }
-            (false, true) => {
-                return Err(BlindBidError::MinimumBidValueUnreached {
-                    min_val: crate::V_MIN,
-                    found: *value,
-                });
+        BidBuilder::new()
+            .stealth_address(*stealth_address)
+            .value(*value)
+            .secret(*secret)
+            .secret_k(secret_k)
+            .eligibility(eligibility)
+            .expiration(expiration)
+            .build(rng)
+    }
+
+    /// Generates a well-formed Bid with a random value inside of the
+    /// `[V_MIN, V_MAX]` range, a random stealth address, secret and
+    /// `secret_k`, and `u64::MAX` as eligibility and expiration.
+    ///
+    /// Meant for property tests and benchmarks only: the secrets of the Bid
+    /// are discarded, so it can't be decrypted nor proven. It is **not** for
+    /// production use.
+    #[cfg(feature = "test-helpers")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "test-helpers")))]
+    pub fn random<R>(rng: &mut R) -> Bid
+    where
+        R: RngCore + CryptoRng,
+    {
+        let pk_r = PublicSpendKey::from(SecretSpendKey::random(rng));
+        let stealth_address =
+            pk_r.gen_stealth_address(&JubJubScalar::random(rng));
+        let secret: JubJubAffine =
+            (GENERATOR_EXTENDED * JubJubScalar::random(rng)).into();
+        // Draws are rejected above the largest multiple of the range length
+        // so every value of the range is equally likely.
+        let span = crate::V_RAW_MAX - crate::V_RAW_MIN + 1;
+        let zone = u64::MAX - u64::MAX % span;
+        let value = loop {
+            let draw = rng.next_u64();
+            if draw < zone {
+                break crate::V_RAW_MIN + draw % span;
             }
-            (false, false) => (),
-            (_, _) => unreachable!(),
         };
-        // Generate an empty Bid and fill it with the correct values
-        let mut bid = Bid {
-            // Compute and add the `hashed_secret` to the Bid.
-            hashed_secret: sponge::hash(&[secret_k]),
+
+        Bid::new(
+            rng,
+            &stealth_address,
+            &JubJubScalar::from(value),
+            &secret,
+            BlsScalar::random(rng),
+            u64::MAX,
+            u64::MAX,
+        )
+        .expect("The value is inside of the allowed range")
+    }
+
+    /// Generates a new Bid using the provided `blinder` instead of sampling
+    /// it, so the commitment of the Bid can be recreated from the blinder
+    /// (e.g. when it's derived from a seed).
+    ///
+    /// The nonce is still sampled from `rng`, or derived from the hashed
+    /// secret and the commitment if the `deterministic-nonce` feature is
+    /// enabled. Use
+    /// [`Bid::new_deterministic`] to provide it as well.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_with_blinder<R>(
+        rng: &mut R,
+        stealth_address: &StealthAddress,
+        value: &JubJubScalar,
+        blinder: &JubJubScalar,
+        secret: &JubJubAffine,
+        secret_k: BlsScalar,
+        eligibility: u64,
+        expiration: u64,
+    ) -> Result<Self, BlindBidError>
+    where
+        R: RngCore + CryptoRng,
+    {
+        Bid::validate_value(value)?;
+        Bid::validate_timestamps(eligibility, expiration)?;
+        let mut bid =
+            Bid::unset(*stealth_address, secret_k, eligibility, expiration);
+        bid.set_value_with_blinder(rng, value, secret, blinder, secret_k);
+        Ok(bid)
+    }
+
+    /// Generates a new Bid using the provided `blinder` and `nonce` instead
+    /// of sampling them, so the same inputs always produce the same Bid.
+    ///
+    /// Meant for test vectors and fixtures: reusing a blinder or a nonce
+    /// across Bids leaks information about their values.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_deterministic(
+        stealth_address: &StealthAddress,
+        value: &JubJubScalar,
+        secret: &JubJubAffine,
+        secret_k: BlsScalar,
+        blinder: &JubJubScalar,
+        nonce: BlsScalar,
+        eligibility: u64,
+        expiration: u64,
+    ) -> Result<Self, BlindBidError> {
+        Bid::validate_value(value)?;
+        Bid::validate_timestamps(eligibility, expiration)?;
+        let mut bid =
+            Bid::unset(*stealth_address, secret_k, eligibility, expiration);
+        bid.set_value_with(value, secret, blinder, nonce);
+        Ok(bid)
+    }
+
+    /// Same as [`Bid::new`] but checking the value against the `[min, max]`
+    /// range instead of the `[V_MIN, V_MAX]` one.
+    ///
+    /// Proofs for Bids that use custom bounds can be generated with the
+    /// [ValueBoundsCircuit](crate::ValueBoundsCircuit).
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_with_bounds<R>(
+        rng: &mut R,
+        stealth_address: &StealthAddress,
+        value: &JubJubScalar,
+        secret: &JubJubAffine,
+        secret_k: BlsScalar,
+        eligibility: u64,
+        expiration: u64,
+        min: JubJubScalar,
+        max: JubJubScalar,
+    ) -> Result<Self, BlindBidError>
+    where
+        R: RngCore + CryptoRng,
+    {
+        BidBuilder::new()
+            .stealth_address(*stealth_address)
+            .value(*value)
+            .secret(*secret)
+            .secret_k(secret_k)
+            .eligibility(eligibility)
+            .expiration(expiration)
+            .bounds(min, max)
+            .build(rng)
+    }
+
+    /// Same as [`Bid::new`] but checking the value against the bounds of
+    /// `tier` instead of the `[V_MIN, V_MAX]` range.
+    ///
+    /// Proofs for Bids of a [`BidTier::Range`] can be generated with the
+    /// [ValueBoundsCircuit](crate::ValueBoundsCircuit).
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_tiered<R>(
+        rng: &mut R,
+        stealth_address: &StealthAddress,
+        value: &JubJubScalar,
+        secret: &JubJubAffine,
+        secret_k: BlsScalar,
+        eligibility: u64,
+        expiration: u64,
+        tier: BidTier,
+    ) -> Result<Self, BlindBidError>
+    where
+        R: RngCore + CryptoRng,
+    {
+        let (min, max) = tier.scalar_bounds();
+        Bid::new_with_bounds(
+            rng,
+            stealth_address,
+            value,
+            secret,
+            secret_k,
             eligibility,
             expiration,
-            c: JubJubAffine::default(),
-            stealth_address: *stealth_address,
-            encrypted_data: PoseidonCipher::default(),
-            nonce: BlsScalar::default(),
-            pos: 0u64,
-        };
+            min,
+            max,
+        )
+    }
+
+    /// Checks that a Bid with the provided timestamps doesn't expire before
+    /// becoming eligible, returning [`BlindBidError::InvalidTimestampOrder`]
+    /// otherwise.
+    ///
+    /// Equal timestamps are accepted, so both of them can be set to
+    /// `u64::MAX` for a Bid that is always valid.
+    pub fn validate_timestamps(
+        eligibility: u64,
+        expiration: u64,
+    ) -> Result<(), BlindBidError> {
+        if expiration < eligibility {
+            return Err(BlindBidError::InvalidTimestampOrder {
+                eligibility,
+                expiration,
+            });
+        }
+        Ok(())
+    }
+
+    /// Checks that a Bid value is inside of the `[V_MIN, V_MAX]` range.
+    ///
+    /// This is the same check performed when a Bid is constructed, so it can
+    /// be used to validate an amount before going through the Bid
+    /// construction and encryption.
+    pub fn validate_value(value: &JubJubScalar) -> Result<(), BlindBidError> {
+        Bid::validate_value_with_bounds(value, &crate::V_MIN, &crate::V_MAX)
+    }
 
-        bid.set_value(rng, value, secret);
+    /// Checks that a Bid value is inside of the `[min, max]` range.
+    pub fn validate_value_with_bounds(
+        value: &JubJubScalar,
+        min: &JubJubScalar,
+        max: &JubJubScalar,
+    ) -> Result<(), BlindBidError> {
+        if !scalar_le(value, max) {
+            return Err(BlindBidError::MaximumBidValueExceeded {
+                max_val: *max,
+                found: *value,
+            });
+        }
+        if !scalar_le(min, value) {
+            return Err(BlindBidError::MinimumBidValueUnreached {
+                min_val: *min,
+                found: *value,
+            });
+        }
+        Ok(())
+    }
 
-        Ok(bid)
+    /// Assembles a Bid from already computed fields, e.g. when the encrypted
+    /// data and the commitment are produced by a separate device.
+    ///
+    /// No check is performed: the caller is responsible of providing a
+    /// commitment and a cipher that hide the same value and blinder, which
+    /// can be checked with [`Bid::decrypt_data`] and
+    /// [`Bid::verify_commitment`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_parts(
+        encrypted_data: PoseidonCipher,
+        nonce: BlsScalar,
+        stealth_address: StealthAddress,
+        hashed_secret: BlsScalar,
+        c: JubJubAffine,
+        eligibility: u64,
+        expiration: u64,
+        pos: u64,
+    ) -> Self {
+        Bid {
+            encrypted_data,
+            nonce,
+            stealth_address,
+            hashed_secret,
+            c,
+            eligibility,
+            expiration,
+            pos,
+            metadata: [0u8; 32],
+        }
     }
 
-    /// Returns the `encrypted_data` field of the Bid.
+    /// Returns the `encrypted_data` field of the Bid: the value & blinder
+    /// encrypted with the secret and the [nonce](Bid::nonce) of the Bid.
+    ///
+    /// The cipher is returned by value since it is `Copy`.
     pub fn encrypted_data(&self) -> PoseidonCipher {
         self.encrypted_data
     }
 
-    /// Returns the `nonce` field of the Bid.
+    /// Returns the `nonce` field of the Bid, used to encrypt its
+    /// [encrypted_data](Bid::encrypted_data).
     pub fn nonce(&self) -> BlsScalar {
         self.nonce
     }
@@ -268,11 +788,32 @@ impl Bid {
         self.c
     }
 
+    /// Returns the serialized `commitment` field of the Bid.
+    pub fn commitment_bytes(&self) -> [u8; 32] {
+        self.c.to_bytes()
+    }
+
     /// Returns the `eligibility` field of the Bid.
     pub fn eligibility(&self) -> u64 {
         self.eligibility
     }
 
+    /// Returns the eligibility of the Bid as the field element used by its
+    /// hash and the [BlindBidCircuit](crate::BlindBidCircuit).
+    ///
+    /// See [`timestamp_to_scalar`].
+    pub fn eligibility_scalar(&self) -> BlsScalar {
+        timestamp_to_scalar(self.eligibility)
+    }
+
+    /// Returns the expiration of the Bid as the field element used by its
+    /// hash and the [BlindBidCircuit](crate::BlindBidCircuit).
+    ///
+    /// See [`timestamp_to_scalar`].
+    pub fn expiration_scalar(&self) -> BlsScalar {
+        timestamp_to_scalar(self.expiration)
+    }
+
     /// Sets a new value for the eligibility of the Bid.
     pub fn set_eligibility(&mut self, new_eligibility: u64) {
         self.eligibility = new_eligibility;
@@ -283,6 +824,58 @@ impl Bid {
         self.expiration
     }
 
+    /// Returns `true` if the Bid can no longer be used to generate a valid
+    /// proof at `latest_consensus_round`.
+    ///
+    /// This matches step 4. of the
+    /// [BlindBidCircuit](crate::BlindBidCircuit), which requires the
+    /// expiration to be **strictly** greater than the round, so a Bid is
+    /// already expired at `latest_consensus_round == expiration`.
+    pub fn is_expired(&self, latest_consensus_round: u64) -> bool {
+        latest_consensus_round >= self.expiration
+    }
+
+    /// Returns `true` if the eligibility of the Bid allows to generate a
+    /// valid proof at `latest_consensus_round`.
+    ///
+    /// This matches step 3. of the
+    /// [BlindBidCircuit](crate::BlindBidCircuit), which requires the
+    /// eligibility to be **strictly** greater than the round, so a Bid is no
+    /// longer eligible at `latest_consensus_round == eligibility`.
+    pub fn is_eligible(&self, latest_consensus_round: u64) -> bool {
+        latest_consensus_round < self.eligibility
+    }
+
+    /// Returns the number of rounds, starting at `latest_consensus_round`,
+    /// during which the Bid is not [expired](Bid::is_expired) yet, or `None`
+    /// if it already is.
+    pub fn rounds_until_expiration(
+        &self,
+        latest_consensus_round: u64,
+    ) -> Option<u64> {
+        match self.is_expired(latest_consensus_round) {
+            true => None,
+            false => Some(self.expiration - latest_consensus_round),
+        }
+    }
+
+    /// Returns the number of rounds, starting at `latest_consensus_round`,
+    /// during which the Bid is still [eligible](Bid::is_eligible), or `None`
+    /// if it no longer is.
+    ///
+    /// A Bid is eligible from its creation until its eligibility round, so
+    /// this is the countdown until the Bid can't be proven anymore, unless
+    /// it expires earlier.
+    pub fn rounds_until_ineligible(
+        &self,
+        latest_consensus_round: u64,
+    ) -> Option<u64> {
+        match self.is_eligible(latest_consensus_round) {
+            true => Some(self.eligibility - latest_consensus_round),
+            false => None,
+        }
+    }
+
     /// Returns a mutable ref pointing to the `pos` field of the
     /// Bid.
     pub fn extend_expiration(&mut self, extension: u64) {
@@ -294,18 +887,58 @@ impl Bid {
         self.pos
     }
 
+    /// Returns the position of the Bid in the Tree where it is stored.
+    ///
+    /// Same as [`Bid::pos`].
+    pub fn position(&self) -> u64 {
+        self.pos
+    }
+
     /// Sets a new value for the position of the Bid.
+    ///
+    /// The position is part of the [`Bid::hash`] preimage, so the hash of the
+    /// Bid changes while its commitment and encrypted data stay the same.
     pub fn set_pos(&mut self, new_pos: u64) {
         self.pos = new_pos;
     }
 
-    /// Performs the [sponge_hash](sponge::hash) techniqe using poseidon to
-    /// compute the one-time prover_id that corresponds to a Bid in an specific
-    /// point of the consensus which is determinated by:
+    /// Returns the same Bid moved to the position `pos` of the tree.
+    ///
+    /// Like with [`Bid::set_pos`], the hash of the returned Bid differs from
+    /// the original one.
+    pub fn with_position(mut self, pos: u64) -> Bid {
+        self.pos = pos;
+        self
+    }
+
+    /// Returns the `metadata` field of the Bid.
+    pub fn metadata(&self) -> &[u8; 32] {
+        &self.metadata
+    }
+
+    /// Sets new opaque application data for the Bid.
+    ///
+    /// The metadata is not part of the consensus: it is excluded from the
+    /// [`Bid::hash`], the commitment and the
+    /// [BlindBidCircuit](crate::BlindBidCircuit). Therefore it can be freely
+    /// modified at any time without invalidating the Bid or its proofs.
+    pub fn set_metadata(&mut self, metadata: [u8; 32]) {
+        self.metadata = metadata;
+    }
+
+    /// Performs the [sponge_hash](dusk_poseidon::sponge::hash) techniqe using
+    /// poseidon to compute the one-time prover_id that corresponds to a Bid in
+    /// an specific point of the consensus which is determinated by:
     /// - consensus_round_seed (sigma^s)
     /// - latest_consensus_round (k^t)
     /// - latest_consensus_step (k^s)
-    /// One-time prover-id is stated to be `H(bid.secret_k, sigma^s, k^t, k^s)`.
+    /// One-time prover-id is stated to be
+    /// `H(DOMAIN_PROVER_ID, secret_k, sigma^s, k^t, k^s)`,
+    /// where `secret_k` is the pre-image of the hashed secret of the Bid.
+    ///
+    /// None of the fields of the Bid are used, so Bids sharing the same
+    /// `secret_k` produce the same prover_id at a given round and step: see
+    /// [`compute_prover_id`], which this method delegates to.
     pub fn generate_prover_id(
         &self,
         secret_k: BlsScalar,
@@ -313,36 +946,186 @@ impl Bid {
         latest_consensus_round: BlsScalar,
         latest_consensus_step: BlsScalar,
     ) -> BlsScalar {
-        sponge::hash(&[
+        compute_prover_id(
+            secret_k,
+            consensus_round_seed,
+            latest_consensus_round,
+            latest_consensus_step,
+        )
+    }
+
+    /// Same as [`Bid::generate_prover_id`] but returning the serialized
+    /// prover_id.
+    pub fn prover_id_bytes(
+        &self,
+        secret_k: BlsScalar,
+        consensus_round_seed: BlsScalar,
+        latest_consensus_round: BlsScalar,
+        latest_consensus_step: BlsScalar,
+    ) -> [u8; 32] {
+        self.generate_prover_id(
             secret_k,
             consensus_round_seed,
             latest_consensus_round,
             latest_consensus_step,
-        ])
+        )
+        .to_bytes()
     }
 
     /// Provided the secret, decripts the data stored inside the
-    /// [cipher](PoseidonCipher) returning a tuple that contains
-    /// the value at stake in the bid and the blinder data which are the two
-    /// values used to generate the bid commitment.
+    /// [cipher](PoseidonCipher) returning the value at stake in the bid and
+    /// the blinder data which are the two values used to generate the bid
+    /// commitment.
+    ///
+    /// The cipher authenticates the secret and the nonce together, so a wrong
+    /// secret can't be told apart from a wrong (e.g. stale) nonce: both make
+    /// this fail with [`BlindBidError::DecryptionFailed`].
+    ///
+    /// Someone probing a Bid with candidate secrets only learns whether the
+    /// decryption succeeded, which the result already tells. To avoid
+    /// leaking anything else through timing, the decrypted scalars are
+    /// reduced on both paths, so a failed decryption takes as long as a
+    /// successful one. The authentication of the cipher itself is performed
+    /// by [`PoseidonCipher::decrypt`].
     pub fn decrypt_data(
         &self,
         secret: &JubJubAffine,
-    ) -> Result<(JubJubScalar, JubJubScalar), BlindBidError> {
-        self.encrypted_data
-            .decrypt(secret, &self.nonce)
-            .map(|message| {
-                let value = message[0];
-                let blinder = message[1];
+    ) -> Result<DecryptedBid, BlindBidError> {
+        let decrypted = self.encrypted_data.decrypt(secret, &self.nonce);
+        let failed = decrypted.is_err();
+        // Reduce a zeroed message on failure instead of returning early.
+        let message = decrypted.unwrap_or_default();
+
+        let value =
+            JubJubScalar::from_raw(*message[0].reduce().internal_repr());
+        let blinder =
+            JubJubScalar::from_raw(*message[1].reduce().internal_repr());
+
+        if failed {
+            return Err(BlindBidError::DecryptionFailed);
+        }
+        Ok(DecryptedBid { value, blinder })
+    }
+
+    /// Returns a copy of the Bid with its value & blinder, decrypted with
+    /// `old_secret`, encrypted under `new_secret`.
+    ///
+    /// The commitment, `hashed_secret`, timestamps, position and metadata of
+    /// the Bid are preserved, so the re-encrypted Bid commits to the same
+    /// value. The value is encrypted with a fresh nonce, unless the
+    /// `deterministic-nonce` feature is enabled, in which case the nonce is
+    /// kept since it only depends on the `hashed_secret` and the commitment.
+    pub fn reencrypt<R>(
+        &self,
+        old_secret: &JubJubAffine,
+        new_secret: &JubJubAffine,
+        rng: &mut R,
+    ) -> Result<Bid, BlindBidError>
+    where
+        R: RngCore + CryptoRng,
+    {
+        let decrypted = self.decrypt_data(old_secret)?;
+
+        cfg_if::cfg_if! {
+            if #[cfg(feature = "deterministic-nonce")] {
+                let _ = rng;
+                let nonce = self.nonce;
+            } else {
+                let nonce = BlsScalar::random(rng);
+            }
+        }
 
-                let value =
-                    JubJubScalar::from_raw(*value.reduce().internal_repr());
-                let blinder =
-                    JubJubScalar::from_raw(*blinder.reduce().internal_repr());
+        let mut bid = *self;
+        bid.set_value_with(
+            &decrypted.value,
+            new_secret,
+            &decrypted.blinder,
+            nonce,
+        );
+        Ok(bid)
+    }
 
-                (value, blinder)
+    /// Decrypts the value & blinder of all of the `bids` that can be
+    /// decrypted with `secret`, returning them paired with the index of
+    /// their Bid in `bids`.
+    ///
+    /// The Bids that fail to decrypt are skipped, so wallets can scan all of
+    /// the candidate Bids at once for the ones they own.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn decrypt_owned(
+        bids: &[Bid],
+        secret: &JubJubAffine,
+    ) -> Vec<(usize, DecryptedBid)> {
+        bids.iter()
+            .enumerate()
+            .filter_map(|(idx, bid)| {
+                bid.decrypt_data(secret)
+                    .ok()
+                    .map(|decrypted| (idx, decrypted))
             })
-            .map_err(|_| BlindBidError::WrongSecretProvided)
+            .collect()
+    }
+
+    /// Same as [`Bid::decrypt_data`] but returning the `(value, blinder)`
+    /// tuple.
+    #[deprecated(note = "Use `Bid::decrypt_data` instead")]
+    pub fn decrypt_data_tuple(
+        &self,
+        secret: &JubJubAffine,
+    ) -> Result<(JubJubScalar, JubJubScalar), BlindBidError> {
+        self.decrypt_data(secret)
+            .map(|decrypted| (decrypted.value(), decrypted.blinder()))
+    }
+
+    /// Returns `true` if the commitment of the Bid is the Pedersen
+    /// commitment `value·G + blinder·G_nums` of the provided values.
+    ///
+    /// Meant to be used with the output of [`Bid::decrypt_data`] to detect
+    /// malformed or tampered commitments before generating a proof.
+    pub fn verify_commitment(
+        &self,
+        value: &JubJubScalar,
+        blinder: &JubJubScalar,
+    ) -> bool {
+        pedersen_commitment(value, blinder) == self.c
+    }
+
+    /// Provided the secret, returns the value at stake in the Bid as a `u64`
+    /// weight suitable for stake-proportional selection.
+    pub fn weight(&self, secret: &JubJubAffine) -> Result<u64, BlindBidError> {
+        let value = self.decrypt_data(secret)?.value();
+        Bid::validate_value(&value)?;
+
+        // The range check guarantees that the value fits in the first 8
+        // bytes of its little-endian representation.
+        let mut weight = [0u8; 8];
+        weight.copy_from_slice(&value.to_bytes()[..8]);
+        Ok(u64::from_le_bytes(weight))
+    }
+
+    /// Derives the nonce of a Bid as `H(DOMAIN_NONCE, hashed_secret, c.x)`.
+    ///
+    /// With the `deterministic-nonce` feature enabled, this is the nonce used
+    /// to encrypt the value & blinder of every Bid. Both inputs are public
+    /// fields of the Bid, so anyone can check with [`Bid::has_derived_nonce`]
+    /// that the nonce wasn't adversarially chosen, without any secret.
+    pub fn derive_nonce(
+        hashed_secret: &BlsScalar,
+        commitment: &JubJubAffine,
+    ) -> BlsScalar {
+        DefaultHasher::hash(&[DOMAIN_NONCE, *hashed_secret, commitment.get_x()])
+    }
+
+    /// Returns `true` if the nonce of the Bid is the one that
+    /// [`Bid::derive_nonce`] derives from its public fields.
+    ///
+    /// The serialized layout of the Bid doesn't depend on how its nonce was
+    /// generated: this check is what tells the Bids created with the
+    /// `deterministic-nonce` feature apart, and it is available with or
+    /// without the feature.
+    pub fn has_derived_nonce(&self) -> bool {
+        self.nonce == Bid::derive_nonce(&self.hashed_secret, &self.c)
     }
 
     pub(crate) fn set_value<R>(
@@ -350,31 +1133,389 @@ impl Bid {
         rng: &mut R,
         value: &JubJubScalar,
         secret: &JubJubAffine,
+        secret_k: BlsScalar,
     ) where
         R: RngCore + CryptoRng,
     {
         let blinder = JubJubScalar::random(rng);
-        self.nonce = BlsScalar::random(rng);
+        self.set_value_with_blinder(rng, value, secret, &blinder, secret_k);
+    }
+
+    /// Sets the commitment, nonce and encrypted data of the Bid from an
+    /// explicit blinder, sampling the nonce.
+    fn set_value_with_blinder<R>(
+        &mut self,
+        rng: &mut R,
+        value: &JubJubScalar,
+        secret: &JubJubAffine,
+        blinder: &JubJubScalar,
+        secret_k: BlsScalar,
+    ) where
+        R: RngCore + CryptoRng,
+    {
+        cfg_if::cfg_if! {
+            if #[cfg(feature = "deterministic-nonce")] {
+                let _ = rng;
+                let nonce = Bid::derive_nonce(
+                    &hash_secret_k(secret_k),
+                    &pedersen_commitment(value, blinder),
+                );
+            } else {
+                let _ = secret_k;
+                let nonce = BlsScalar::random(rng);
+            }
+        }
+        self.set_value_with(value, secret, blinder, nonce);
+    }
+
+    /// Sets the commitment, nonce and encrypted data of the Bid from an
+    /// explicit blinder and nonce.
+    fn set_value_with(
+        &mut self,
+        value: &JubJubScalar,
+        secret: &JubJubAffine,
+        blinder: &JubJubScalar,
+        nonce: BlsScalar,
+    ) {
+        self.c = pedersen_commitment(value, blinder);
+        self.nonce = nonce;
         self.encrypted_data = PoseidonCipher::encrypt(
-            &[(*value).into(), blinder.into()],
+            &[(*value).into(), (*blinder).into()],
             secret,
             &self.nonce,
         );
+    }
 
-        self.c = JubJubAffine::from(
-            &(GENERATOR_EXTENDED * value)
-                + &(GENERATOR_NUMS_EXTENDED * blinder),
-        );
+    /// Returns a Bid with the provided fields and no value set.
+    fn unset(
+        stealth_address: StealthAddress,
+        secret_k: BlsScalar,
+        eligibility: u64,
+        expiration: u64,
+    ) -> Self {
+        Bid {
+            // Compute and add the `hashed_secret` to the Bid.
+            hashed_secret: hash_secret_k(secret_k),
+            eligibility,
+            expiration,
+            c: JubJubAffine::default(),
+            stealth_address,
+            encrypted_data: PoseidonCipher::default(),
+            nonce: BlsScalar::default(),
+            pos: 0u64,
+            metadata: [0u8; 32],
+        }
     }
 }
 
-#[cfg(feature = "std")]
-#[cfg(test)]
-mod bid_serialization {
-    use super::*;
-    use crate::{V_RAW_MAX, V_RAW_MIN};
-    use dusk_pki::{PublicSpendKey, SecretSpendKey};
-    use rand::Rng;
+/// Returns the generator the value of a Bid is multiplied by in its
+/// commitment `c = value·G + blinder·G_nums`, which is `GENERATOR_EXTENDED`
+/// of `dusk-jubjub`.
+///
+/// Both the native commitment and the gadget of the
+/// [BlindBidCircuit](crate::BlindBidCircuit) use this generator.
+pub fn value_generator() -> JubJubExtended {
+    GENERATOR_EXTENDED
+}
+
+/// Returns the generator the blinder of a Bid is multiplied by in its
+/// commitment `c = value·G + blinder·G_nums`, which is
+/// `GENERATOR_NUMS_EXTENDED` of `dusk-jubjub`.
+///
+/// Both the native commitment and the gadget of the
+/// [BlindBidCircuit](crate::BlindBidCircuit) use this generator.
+pub fn blinder_generator() -> JubJubExtended {
+    GENERATOR_NUMS_EXTENDED
+}
+
+/// Computes the Pedersen commitment of the value and blinder of a Bid.
+fn pedersen_commitment(
+    value: &JubJubScalar,
+    blinder: &JubJubScalar,
+) -> JubJubAffine {
+    JubJubAffine::from(
+        &(value_generator() * value) + &(blinder_generator() * blinder),
+    )
+}
+
+/// Computes the hashed secret `H(DOMAIN_HASHED_SECRET, secret_k)` stored in
+/// the Bids created with `secret_k`.
+///
+/// The [BlindBidCircuit](crate::BlindBidCircuit) proves the knowledge of
+/// `secret_k` by checking this same hash against the hashed secret of the Bid.
+/// The [`DOMAIN_HASHED_SECRET`] tag keeps it from colliding with the other
+/// hashes taking `secret_k` as input, such as the prover_id.
+pub fn hash_secret_k(secret_k: BlsScalar) -> BlsScalar {
+    DefaultHasher::hash(&[DOMAIN_HASHED_SECRET, secret_k])
+}
+
+/// Computes the one-time prover_id
+/// `H(DOMAIN_PROVER_ID, secret_k, sigma^s, k^t, k^s)` of the
+/// owner of `secret_k` at the consensus round and step determinated by
+/// `consensus_round_seed` (sigma^s), `latest_consensus_round` (k^t) and
+/// `latest_consensus_step` (k^s).
+///
+/// The prover_id intentionally doesn't depend on the data of any Bid: it
+/// identifies the owner of `secret_k`, so all of the Bids sharing it produce
+/// the same prover_id at a given round and step, and
+/// [validate_candidate_set](crate::validate_candidate_set) can reject a
+/// prover submitting several of them. The prover_id is bound to a Bid by
+/// the [BlindBidCircuit](crate::BlindBidCircuit) instead, which checks that
+/// `H(DOMAIN_HASHED_SECRET, secret_k)` is the hashed secret of the Bid being
+/// proven.
+pub fn compute_prover_id(
+    secret_k: BlsScalar,
+    consensus_round_seed: BlsScalar,
+    latest_consensus_round: BlsScalar,
+    latest_consensus_step: BlsScalar,
+) -> BlsScalar {
+    DefaultHasher::hash(&[
+        DOMAIN_PROVER_ID,
+        secret_k,
+        consensus_round_seed,
+        latest_consensus_round,
+        latest_consensus_step,
+    ])
+}
+
+/// Converts a `u64` timestamp (a consensus round, or the eligibility and
+/// expiration heights of a Bid) into the field representation used by the
+/// hash of the Bid and the [BlindBidCircuit](crate::BlindBidCircuit).
+///
+/// The timestamp is placed in the lowest 64 bits of the `BlsScalar`, so the
+/// conversion is injective and preserves the ordering of the timestamps: the
+/// comparison gadgets of the circuit rely on every timestamp fitting in 64
+/// bits.
+pub fn timestamp_to_scalar(t: u64) -> BlsScalar {
+    BlsScalar::from(t)
+}
+
+/// Checks a batch of candidate Bid values against the `[V_MIN, V_MAX]` range
+/// returning the result of the check for each one of them **in order**.
+///
+/// This allows to report all of the out-of-range values at once instead of
+/// failing on the first one as [`Bid::new`] does.
+#[cfg(feature = "std")]
+pub fn validate_bid_values(
+    values: &[JubJubScalar],
+) -> Vec<Result<(), BlindBidError>> {
+    values.iter().map(Bid::validate_value).collect()
+}
+
+/// Returns `true` if `value` is inside of the `[V_MIN, V_MAX]` range accepted
+/// for the value of a Bid.
+///
+/// The comparison is performed on the canonical encodings of the scalars (see
+/// [`scalar_le`]), never on their Montgomery representation. For a value that
+/// fits in a `u64`, the canonical encoding is exactly the little-endian bytes
+/// of the integer followed by zeroes, so this is the plain integer comparison
+/// `V_RAW_MIN <= value <= V_RAW_MAX`. Any other scalar has a non-zero byte
+/// above the lowest 8 and is therefore bigger than [`V_RAW_MAX`].
+pub fn value_in_range(value: &JubJubScalar) -> bool {
+    scalar_le(&crate::V_MIN, value) && scalar_le(value, &crate::V_MAX)
+}
+
+/// Returns `true` if the integer represented by `a` is lower or equal than the
+/// integer represented by `b`.
+///
+/// `JubJubScalar`s are stored in Montgomery form, so neither comparing them
+/// directly nor comparing the outputs of `reduce()` is guaranteed to follow
+/// the ordering of the integers they represent. This compares the canonical
+/// little-endian encodings of both scalars starting by the most significant
+/// byte instead.
+pub(crate) fn scalar_le(a: &JubJubScalar, b: &JubJubScalar) -> bool {
+    a.to_bytes().iter().rev().le(b.to_bytes().iter().rev())
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod deterministic_nonce {
+    use super::*;
+    use crate::V_RAW_MIN;
+    use dusk_pki::{PublicSpendKey, SecretSpendKey};
+
+    fn bid_and_secret() -> (Bid, JubJubAffine, BlsScalar, JubJubScalar) {
+        let mut rng = rand::thread_rng();
+        let pk_r = PublicSpendKey::from(SecretSpendKey::random(&mut rng));
+        let secret = JubJubScalar::random(&mut rng);
+        let stealth_addr = pk_r.gen_stealth_address(&secret);
+        let secret: JubJubAffine = (GENERATOR_EXTENDED * secret).into();
+        let secret_k = BlsScalar::random(&mut rng);
+        let value = JubJubScalar::from(V_RAW_MIN);
+
+        let bid = Bid::new(
+            &mut rng,
+            &stealth_addr,
+            &value,
+            &secret,
+            secret_k,
+            u64::MAX,
+            u64::MAX,
+        )
+        .expect("Bid creation error");
+        (bid, secret, secret_k, value)
+    }
+
+    #[test]
+    #[cfg(feature = "deterministic-nonce")]
+    fn nonce_is_reproducible() {
+        let (bid, secret, _, value) = bid_and_secret();
+
+        assert_eq!(
+            bid.nonce(),
+            Bid::derive_nonce(&bid.hashed_secret(), &bid.commitment())
+        );
+        assert_ne!(
+            bid.nonce(),
+            Bid::derive_nonce(
+                &BlsScalar::random(&mut rand::thread_rng()),
+                &bid.commitment()
+            )
+        );
+        assert!(bid.has_derived_nonce());
+        let decrypted = bid.decrypt_data(&secret).expect("Decryption error");
+        assert_eq!(decrypted.value(), value);
+    }
+
+    #[test]
+    #[cfg(not(feature = "deterministic-nonce"))]
+    fn sampled_nonce_is_not_derived() {
+        let (bid, _, _, _) = bid_and_secret();
+        assert!(!bid.has_derived_nonce());
+    }
+
+    #[test]
+    fn derived_nonce_only_needs_public_fields() {
+        let (bid, _, secret_k, _) = bid_and_secret();
+        assert_eq!(
+            Bid::derive_nonce(&bid.hashed_secret(), &bid.commitment()),
+            Bid::derive_nonce(&hash_secret_k(secret_k), &bid.commitment())
+        );
+    }
+}
+
+#[cfg(test)]
+mod scalar_comparison {
+    use super::*;
+    use crate::{V_MAX, V_MIN, V_RAW_MAX, V_RAW_MIN};
+
+    #[test]
+    fn scalar_le_is_an_integer_comparison() {
+        assert!(scalar_le(&V_MIN, &V_MIN));
+        assert!(scalar_le(&V_MIN, &V_MAX));
+        assert!(!scalar_le(&V_MAX, &V_MIN));
+        assert!(scalar_le(
+            &JubJubScalar::from(V_RAW_MAX),
+            &JubJubScalar::from(V_RAW_MAX + 1)
+        ));
+        assert!(!scalar_le(
+            &JubJubScalar::from(V_RAW_MIN),
+            &JubJubScalar::from(V_RAW_MIN - 1)
+        ));
+        // Values close to the modulus are the biggest integers of the field.
+        assert!(scalar_le(&V_MAX, &-JubJubScalar::one()));
+        assert!(!scalar_le(&-JubJubScalar::one(), &V_MAX));
+        assert!(scalar_le(&JubJubScalar::zero(), &-JubJubScalar::one()));
+        // Values bigger than 2^64 only differ on the most significant limbs.
+        let big = JubJubScalar::from_raw([0, 1, 0, 0]);
+        assert!(scalar_le(&V_MAX, &big));
+        assert!(!scalar_le(&big, &V_MAX));
+    }
+
+    #[test]
+    fn value_in_range_boundaries() {
+        assert!(!value_in_range(&JubJubScalar::from(V_RAW_MIN - 1)));
+        assert!(value_in_range(&JubJubScalar::from(V_RAW_MIN)));
+        assert!(value_in_range(&JubJubScalar::from(V_RAW_MAX)));
+        assert!(!value_in_range(&JubJubScalar::from(V_RAW_MAX + 1)));
+        assert!(!value_in_range(&JubJubScalar::zero()));
+        assert!(!value_in_range(&-JubJubScalar::from(V_RAW_MIN)));
+        assert!(!value_in_range(&JubJubScalar::from_raw([
+            V_RAW_MIN, 1, 0, 0
+        ])));
+    }
+
+    #[test]
+    fn value_range_getters() {
+        assert_eq!(crate::v_min(), JubJubScalar::from(V_RAW_MIN));
+        assert_eq!(crate::v_max(), JubJubScalar::from(V_RAW_MAX));
+    }
+}
+
+#[cfg(all(feature = "std", feature = "zeroize"))]
+#[cfg(test)]
+mod zeroization {
+    use super::*;
+    use crate::V_RAW_MIN;
+    use dusk_pki::{PublicSpendKey, SecretSpendKey};
+
+    fn wiped_on_drop<T: ZeroizeOnDrop>(_: &T) {}
+
+    #[test]
+    fn decrypted_data_is_wiped() {
+        let mut rng = rand::thread_rng();
+        let pk_r = PublicSpendKey::from(SecretSpendKey::random(&mut rng));
+        let secret = JubJubScalar::random(&mut rng);
+        let stealth_addr = pk_r.gen_stealth_address(&secret);
+        let secret: JubJubAffine = (GENERATOR_EXTENDED * secret).into();
+        let bid = Bid::new(
+            &mut rng,
+            &stealth_addr,
+            &JubJubScalar::from(V_RAW_MIN),
+            &secret,
+            BlsScalar::one(),
+            u64::MAX,
+            u64::MAX,
+        )
+        .expect("Bid creation error");
+
+        let mut decrypted =
+            bid.decrypt_data(&secret).expect("Decryption error");
+        wiped_on_drop(&decrypted);
+        assert_eq!(decrypted.value(), JubJubScalar::from(V_RAW_MIN));
+
+        decrypted.zeroize();
+        assert_eq!(decrypted.value(), JubJubScalar::zero());
+        assert_eq!(decrypted.blinder(), JubJubScalar::zero());
+    }
+
+    #[test]
+    fn decrypted_data_is_wiped_by_drop() {
+        let mut rng = rand::thread_rng();
+        let pk_r = PublicSpendKey::from(SecretSpendKey::random(&mut rng));
+        let secret: JubJubAffine =
+            (GENERATOR_EXTENDED * JubJubScalar::random(&mut rng)).into();
+        let bid = Bid::new(
+            &mut rng,
+            &pk_r.gen_stealth_address(&JubJubScalar::one()),
+            &JubJubScalar::from(V_RAW_MIN),
+            &secret,
+            BlsScalar::one(),
+            u64::MAX,
+            u64::MAX,
+        )
+        .expect("Bid creation error");
+
+        // Run the `Drop` impl in place while keeping the memory around, so
+        // the fields can be read afterwards. Both of them are plain scalars,
+        // so reading them after the drop is sound.
+        let mut decrypted = core::mem::ManuallyDrop::new(
+            bid.decrypt_data(&secret).expect("Decryption error"),
+        );
+        assert_ne!(decrypted.blinder(), JubJubScalar::zero());
+        unsafe { core::ptr::drop_in_place(&mut *decrypted) };
+        assert_eq!(decrypted.value, JubJubScalar::zero());
+        assert_eq!(decrypted.blinder, JubJubScalar::zero());
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod bid_serialization {
+    use super::*;
+    use crate::{V_RAW_MAX, V_RAW_MIN};
+    use dusk_pki::{PublicSpendKey, SecretSpendKey};
+    use rand::Rng;
 
     #[test]
     fn bid_serialization_roundtrip() {
@@ -411,6 +1552,907 @@ mod bid_serialization {
         let bid_bytes = bid.to_bytes();
         let bid_from_bytes =
             Bid::from_bytes(&bid_bytes).expect("Invalid roundtrip");
-        assert_eq!(bid, bid_from_bytes)
+        assert_eq!(bid, bid_from_bytes);
+        assert_eq!(bid.to_bytes()[..], bid_from_bytes.to_bytes()[..]);
+    }
+
+    /// Generates a Bid of `value` with a random stealth address and secret,
+    /// returned along with it, `BlsScalar::one()` as `secret_k` and
+    /// `u64::MAX` as eligibility and expiration.
+    fn bid_with_value(
+        value: JubJubScalar,
+    ) -> Result<(Bid, JubJubAffine), BlindBidError> {
+        let mut rng = rand::thread_rng();
+        let pk_r = PublicSpendKey::from(SecretSpendKey::random(&mut rng));
+        let secret = JubJubScalar::random(&mut rng);
+        let stealth_addr = pk_r.gen_stealth_address(&secret);
+        let secret: JubJubAffine = (GENERATOR_EXTENDED * secret).into();
+
+        let bid = Bid::new(
+            &mut rng,
+            &stealth_addr,
+            &value,
+            &secret,
+            BlsScalar::one(),
+            u64::MAX,
+            u64::MAX,
+        )?;
+        Ok((bid, secret))
+    }
+
+    #[test]
+    fn decrypted_value_as_u64() {
+        let (bid, secret) = bid_with_value(JubJubScalar::from(100_000u64))
+            .expect("Bid creation error");
+        let decrypted = bid.decrypt_data(&secret).expect("Decryption error");
+        assert_eq!(decrypted.value_u64(), Some(100_000));
+
+        let decrypted = |value: JubJubScalar| DecryptedBid {
+            value,
+            blinder: JubJubScalar::one(),
+        };
+        assert_eq!(
+            decrypted(JubJubScalar::from(u64::MAX)).value_u64(),
+            Some(u64::MAX)
+        );
+        assert_eq!(
+            decrypted(JubJubScalar::from(u64::MAX) + JubJubScalar::one())
+                .value_u64(),
+            None
+        );
+        assert_eq!(decrypted(-JubJubScalar::one()).value_u64(), None);
+    }
+
+    #[test]
+    fn cipher_and_nonce_accessors() {
+        let mut rng = rand::thread_rng();
+        let value = JubJubScalar::from(V_RAW_MIN);
+        let (template, secret) =
+            bid_with_value(value).expect("Bid creation error");
+        let blinder = JubJubScalar::random(&mut rng);
+        let bid = Bid::new_with_blinder(
+            &mut rng,
+            template.stealth_address(),
+            &value,
+            &blinder,
+            &secret,
+            BlsScalar::one(),
+            u64::MAX,
+            u64::MAX,
+        )
+        .expect("Bid creation error");
+
+        let expected = PoseidonCipher::encrypt(
+            &[value.into(), blinder.into()],
+            &secret,
+            &bid.nonce(),
+        );
+        assert_eq!(bid.encrypted_data().cipher(), expected.cipher());
+        assert_eq!(
+            Bid::from_bytes(&bid.to_bytes()).unwrap().nonce(),
+            bid.nonce()
+        );
+    }
+
+    #[test]
+    fn rounds_until_timestamps() {
+        let (bid, _) = bid_with_value(JubJubScalar::from(V_RAW_MIN))
+            .expect("Bid creation error");
+        let bid = Bid::from_parts(
+            bid.encrypted_data(),
+            bid.nonce(),
+            *bid.stealth_address(),
+            bid.hashed_secret(),
+            bid.commitment(),
+            100,
+            200,
+            0,
+        );
+
+        assert_eq!(bid.rounds_until_expiration(0), Some(200));
+        assert_eq!(bid.rounds_until_expiration(190), Some(10));
+        assert_eq!(bid.rounds_until_expiration(199), Some(1));
+        assert_eq!(bid.rounds_until_expiration(200), None);
+        assert_eq!(bid.rounds_until_expiration(u64::MAX), None);
+
+        assert_eq!(bid.rounds_until_ineligible(0), Some(100));
+        assert_eq!(bid.rounds_until_ineligible(90), Some(10));
+        assert_eq!(bid.rounds_until_ineligible(99), Some(1));
+        assert_eq!(bid.rounds_until_ineligible(100), None);
+        assert_eq!(bid.rounds_until_ineligible(u64::MAX), None);
+    }
+
+    #[test]
+    fn decrypt_owned_bids() {
+        let (template, secret) = bid_with_value(JubJubScalar::from(V_RAW_MIN))
+            .expect("Bid creation error");
+        let owned = |value: u64| {
+            Bid::new(
+                &mut rand::thread_rng(),
+                template.stealth_address(),
+                &JubJubScalar::from(value),
+                &secret,
+                BlsScalar::one(),
+                u64::MAX,
+                u64::MAX,
+            )
+            .expect("Bid creation error")
+        };
+        let (not_owned, _) = bid_with_value(JubJubScalar::from(V_RAW_MIN))
+            .expect("Bid creation error");
+
+        let bids = [
+            not_owned,
+            owned(V_RAW_MIN),
+            not_owned,
+            not_owned,
+            owned(V_RAW_MAX),
+        ];
+        let decrypted = Bid::decrypt_owned(&bids, &secret);
+        assert_eq!(decrypted.len(), 2);
+        assert_eq!(decrypted[0].0, 1);
+        assert_eq!(decrypted[0].1.value(), JubJubScalar::from(V_RAW_MIN));
+        assert_eq!(decrypted[1].0, 4);
+        assert_eq!(decrypted[1].1.value(), JubJubScalar::from(V_RAW_MAX));
+        assert!(Bid::decrypt_owned(&bids[2..4], &secret).is_empty());
+    }
+
+    #[test]
+    fn bid_value_range_boundaries() {
+        assert!(bid_with_value(JubJubScalar::from(V_RAW_MIN)).is_ok());
+        assert!(bid_with_value(JubJubScalar::from(V_RAW_MAX)).is_ok());
+        assert!(matches!(
+            bid_with_value(JubJubScalar::from(V_RAW_MIN - 1)),
+            Err(BlindBidError::MinimumBidValueUnreached { .. })
+        ));
+        assert!(matches!(
+            bid_with_value(JubJubScalar::from(V_RAW_MAX + 1)),
+            Err(BlindBidError::MaximumBidValueExceeded { .. })
+        ));
+        assert!(matches!(
+            bid_with_value(-JubJubScalar::one()),
+            Err(BlindBidError::MaximumBidValueExceeded { .. })
+        ));
+    }
+
+    #[test]
+    fn timestamp_order() {
+        assert!(Bid::validate_timestamps(10, 20).is_ok());
+        assert!(Bid::validate_timestamps(20, 20).is_ok());
+        assert!(Bid::validate_timestamps(u64::MAX, u64::MAX).is_ok());
+        assert!(matches!(
+            Bid::validate_timestamps(21, 20),
+            Err(BlindBidError::InvalidTimestampOrder {
+                eligibility: 21,
+                expiration: 20
+            })
+        ));
+
+        let (template, secret) = bid_with_value(JubJubScalar::from(V_RAW_MIN))
+            .expect("Bid creation error");
+        let new_bid = |eligibility, expiration| {
+            Bid::new(
+                &mut rand::thread_rng(),
+                template.stealth_address(),
+                &JubJubScalar::from(V_RAW_MIN),
+                &secret,
+                BlsScalar::one(),
+                eligibility,
+                expiration,
+            )
+        };
+        assert!(new_bid(20, 20).is_ok());
+        assert!(matches!(
+            new_bid(21, 20),
+            Err(BlindBidError::InvalidTimestampOrder { .. })
+        ));
+    }
+
+    #[test]
+    fn expiration_and_eligibility_boundaries() {
+        let (mut bid, _) = bid_with_value(JubJubScalar::from(V_RAW_MIN))
+            .expect("Bid creation error");
+        bid.eligibility = 10;
+        bid.expiration = 20;
+
+        assert!(bid.is_eligible(9));
+        assert!(!bid.is_eligible(10));
+        assert!(!bid.is_eligible(11));
+
+        assert!(!bid.is_expired(19));
+        assert!(bid.is_expired(20));
+        assert!(bid.is_expired(21));
+    }
+
+    #[test]
+    fn serialization_into_buffer() {
+        let (bid, _) = bid_with_value(JubJubScalar::from(V_RAW_MIN))
+            .expect("Bid creation error");
+
+        let mut buf = [0xffu8; Bid::SIZE + 8];
+        bid.to_bytes_into(&mut buf).expect("Serialization error");
+        assert_eq!(buf[..Bid::SIZE], bid.to_bytes()[..]);
+        assert_eq!(buf[Bid::SIZE..], [0xffu8; 8]);
+
+        assert!(matches!(
+            bid.to_bytes_into(&mut buf[..Bid::SIZE - 1]),
+            Err(dusk_bytes::Error::BadLength { .. })
+        ));
+    }
+
+    #[test]
+    fn truncated_buffer_decoding() {
+        fn decode(bytes: &[u8]) -> Result<Bid, BlindBidError> {
+            Ok(Bid::from_slice(bytes)?)
+        }
+
+        let (bid, _) = bid_with_value(JubJubScalar::from(V_RAW_MIN))
+            .expect("Bid creation error");
+        let bytes = bid.to_bytes();
+
+        assert_eq!(decode(&bytes).expect("Decoding error"), bid);
+        assert!(matches!(
+            decode(&bytes[..Bid::SIZE - 1]),
+            Err(BlindBidError::SerializationError(
+                dusk_bytes::Error::BadLength { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn legacy_layout() {
+        let (mut bid, _) = bid_with_value(JubJubScalar::from(V_RAW_MIN))
+            .expect("Bid creation error");
+        let mut legacy = [0u8; BID_LEGACY_SERIALIZED_SIZE];
+        legacy.copy_from_slice(&bid.to_bytes()[..BID_LEGACY_SERIALIZED_SIZE]);
+        bid.set_metadata([0xab; 32]);
+
+        let decoded = Bid::from_legacy_bytes(&legacy).expect("Decoding error");
+        assert_eq!(decoded.metadata(), &[0u8; 32]);
+        assert_eq!(decoded.hash(), bid.hash());
+        bid.set_metadata([0u8; 32]);
+        assert_eq!(decoded, bid);
+        assert_eq!(Bid::try_from(&legacy[..]).expect("Decoding error"), bid);
+    }
+
+    #[test]
+    fn versioned_legacy_layout() {
+        let (bid, _) = bid_with_value(JubJubScalar::from(V_RAW_MIN))
+            .expect("Bid creation error");
+        // Blob emitted by peers before the metadata was introduced: the
+        // version `0` tag followed by the 280-byte layout.
+        let mut legacy = vec![0u8];
+        legacy.extend_from_slice(&bid.to_bytes()[..BID_LEGACY_SERIALIZED_SIZE]);
+        assert_eq!(legacy.len(), 281);
+
+        assert_eq!(
+            Bid::from_bytes_versioned(&legacy).expect("Decoding error"),
+            bid
+        );
+        assert!(matches!(
+            Bid::from_bytes_versioned(&legacy[..280]),
+            Err(BlindBidError::SerializationError(
+                dusk_bytes::Error::BadLength { found: 279, .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn slice_conversion() {
+        let (bid, _) = bid_with_value(JubJubScalar::from(V_RAW_MIN))
+            .expect("Bid creation error");
+        let mut bytes = bid.to_bytes().to_vec();
+
+        assert_eq!(Bid::try_from(&bytes[..]).expect("Decoding error"), bid);
+        assert!(matches!(
+            Bid::try_from(&bytes[..Bid::SIZE - 1]),
+            Err(BlindBidError::SerializationError(
+                dusk_bytes::Error::BadLength { found, .. }
+            )) if found == Bid::SIZE - 1
+        ));
+        bytes.push(0);
+        assert!(matches!(
+            Bid::try_from(&bytes[..]),
+            Err(BlindBidError::SerializationError(
+                dusk_bytes::Error::BadLength { found, .. }
+            )) if found == Bid::SIZE + 1
+        ));
+    }
+
+    #[test]
+    fn bid_bytes_alias() {
+        let (bid, _) = bid_with_value(JubJubScalar::from(V_RAW_MIN))
+            .expect("Bid creation error");
+        let bytes: BidBytes = bid.to_bytes();
+        assert_eq!(bytes.len(), Bid::SIZE);
+        assert_eq!(core::mem::size_of::<BidBytes>(), BID_SERIALIZED_SIZE);
+    }
+
+    #[test]
+    fn bids_as_map_keys() {
+        use std::collections::HashMap;
+
+        let (bid, _) = bid_with_value(JubJubScalar::from(V_RAW_MIN))
+            .expect("Bid creation error");
+        let mut map = HashMap::new();
+        map.insert(bid, 1);
+        map.insert(Bid::from_bytes(&bid.to_bytes()).expect("Invalid bid"), 2);
+        assert_eq!(map.len(), 1);
+        assert_eq!(map[&bid], 2);
+
+        map.insert(bid.with_position(bid.pos() + 1), 3);
+        assert_eq!(map.len(), 2);
+        assert_eq!(map[&bid], 2);
+    }
+
+    #[test]
+    fn timestamps_and_pos_are_little_endian() {
+        let (bid, _) = bid_with_value(JubJubScalar::from(V_RAW_MIN))
+            .expect("Bid creation error");
+        let bid = Bid::from_parts(
+            bid.encrypted_data(),
+            bid.nonce(),
+            *bid.stealth_address(),
+            bid.hashed_secret(),
+            bid.commitment(),
+            0x0102_0304_0506_0708,
+            0x1112_1314_1516_1718,
+            0x2122_2324_2526_2728,
+        );
+
+        let bytes = bid.to_bytes();
+        assert_eq!(bytes[256..264], [8, 7, 6, 5, 4, 3, 2, 1]);
+        assert_eq!(
+            bytes[264..272],
+            [0x18, 0x17, 0x16, 0x15, 0x14, 0x13, 0x12, 0x11]
+        );
+        assert_eq!(
+            bytes[272..280],
+            [0x28, 0x27, 0x26, 0x25, 0x24, 0x23, 0x22, 0x21]
+        );
+        assert_eq!(
+            Bid::from_bytes(&bytes).expect("Invalid bid").pos(),
+            0x2122_2324_2526_2728
+        );
+    }
+
+    #[test]
+    fn wire_schema_matches_the_layout() {
+        let schema: serde_json::Value =
+            serde_json::from_str(Bid::wire_schema()).expect("Invalid schema");
+        assert_eq!(schema["size"], Bid::SIZE);
+
+        // The fields are contiguous and cover the whole representation.
+        let fields = schema["fields"].as_array().expect("Missing fields");
+        let end = fields.iter().fold(0, |offset, field| {
+            assert_eq!(field["offset"], offset);
+            offset + field["size"].as_u64().expect("Missing size")
+        });
+        assert_eq!(end as usize, Bid::SIZE);
+
+        let (bid, _) = bid_with_value(JubJubScalar::from(V_RAW_MIN))
+            .expect("Bid creation error");
+        let bytes = bid.to_bytes();
+        let pos = fields
+            .iter()
+            .find(|field| field["name"] == "pos")
+            .expect("Missing pos");
+        let offset = pos["offset"].as_u64().expect("Missing offset") as usize;
+        assert_eq!(bytes[offset..offset + 8], bid.pos().to_le_bytes());
+    }
+
+    #[test]
+    fn serialized_size() {
+        let (bid, _) = bid_with_value(JubJubScalar::from(V_RAW_MIN))
+            .expect("Bid creation error");
+
+        assert_eq!(BID_SERIALIZED_SIZE, bid.to_bytes().len());
+        assert_eq!(BID_SERIALIZED_SIZE + 1, bid.to_bytes_versioned().len());
+    }
+
+    #[test]
+    fn versioned_serialization() {
+        let (bid, _) = bid_with_value(JubJubScalar::from(V_RAW_MIN))
+            .expect("Bid creation error");
+
+        let bytes = bid.to_bytes_versioned();
+        assert_eq!(bytes[0], BID_FORMAT_VERSION);
+        assert_eq!(bytes[1..], bid.to_bytes()[..]);
+        assert_eq!(
+            Bid::from_bytes_versioned(&bytes).expect("Decoding error"),
+            bid
+        );
+
+        let mut unknown = bytes;
+        unknown[0] = BID_FORMAT_VERSION + 1;
+        assert!(matches!(
+            Bid::from_bytes_versioned(&unknown),
+            Err(BlindBidError::UnsupportedVersion(2))
+        ));
+        assert!(matches!(
+            Bid::from_bytes_versioned(&bytes[..Bid::SIZE]),
+            Err(BlindBidError::SerializationError(_))
+        ));
+        assert!(Bid::from_bytes_versioned(&[]).is_err());
+    }
+
+    #[test]
+    fn prover_id_without_bid() {
+        let mut rng = rand::thread_rng();
+        let (bid, _) = bid_with_value(JubJubScalar::from(V_RAW_MIN))
+            .expect("Bid creation error");
+        let secret_k = BlsScalar::random(&mut rng);
+        let seed = BlsScalar::random(&mut rng);
+        let round = BlsScalar::from(10u64);
+        let step = BlsScalar::from(2u64);
+
+        assert_eq!(
+            bid.generate_prover_id(secret_k, seed, round, step),
+            compute_prover_id(secret_k, seed, round, step)
+        );
+    }
+
+    #[test]
+    fn prover_id_identifies_the_owner() {
+        let mut rng = rand::thread_rng();
+        let seed = BlsScalar::random(&mut rng);
+        let round = BlsScalar::from(10u64);
+        let step = BlsScalar::from(2u64);
+        let (bid, _) = bid_with_value(JubJubScalar::from(V_RAW_MIN))
+            .expect("Bid creation error");
+        let (other_bid, _) = bid_with_value(JubJubScalar::from(V_RAW_MAX))
+            .expect("Bid creation error");
+
+        // Distinct owners always get distinct prover ids.
+        let secret_k = BlsScalar::random(&mut rng);
+        let other_secret_k = BlsScalar::random(&mut rng);
+        assert_ne!(
+            bid.generate_prover_id(secret_k, seed, round, step),
+            other_bid.generate_prover_id(other_secret_k, seed, round, step)
+        );
+
+        // While the Bids of the same owner share it at a given round & step.
+        assert_eq!(
+            bid.generate_prover_id(secret_k, seed, round, step),
+            other_bid.generate_prover_id(secret_k, seed, round, step)
+        );
+        assert_ne!(
+            bid.generate_prover_id(secret_k, seed, round, step),
+            bid.generate_prover_id(secret_k, seed, round + step, step)
+        );
+    }
+
+    #[test]
+    fn commitment_verification() {
+        let (bid, secret) = bid_with_value(JubJubScalar::from(V_RAW_MIN))
+            .expect("Bid creation error");
+
+        let decrypted = bid.decrypt_data(&secret).expect("Decryption error");
+        assert!(bid.verify_commitment(&decrypted.value(), &decrypted.blinder()));
+        assert!(
+            !bid.verify_commitment(&decrypted.blinder(), &decrypted.value())
+        );
+
+        let mut tampered = bid;
+        tampered.c =
+            (GENERATOR_EXTENDED * JubJubScalar::from(V_RAW_MAX)).into();
+        assert!(!tampered
+            .verify_commitment(&decrypted.value(), &decrypted.blinder()));
+    }
+
+    #[test]
+    fn position_rotation() {
+        let (bid, _) = bid_with_value(JubJubScalar::from(V_RAW_MIN))
+            .expect("Bid creation error");
+        let moved = bid.with_position(bid.pos() + 5);
+
+        assert_eq!(moved.position(), bid.pos() + 5);
+        assert_eq!(moved.commitment(), bid.commitment());
+        assert_eq!(
+            moved.encrypted_data().cipher(),
+            bid.encrypted_data().cipher()
+        );
+        assert_ne!(moved.hash(), bid.hash());
+    }
+
+    #[test]
+    fn assembly_from_parts() {
+        let value = JubJubScalar::from(V_RAW_MIN);
+        let (bid, secret) = bid_with_value(value).expect("Bid creation error");
+
+        let assembled = Bid::from_parts(
+            bid.encrypted_data(),
+            bid.nonce(),
+            *bid.stealth_address(),
+            bid.hashed_secret(),
+            bid.commitment(),
+            bid.eligibility(),
+            bid.expiration(),
+            bid.pos(),
+        );
+        assert_eq!(assembled.to_bytes()[..], bid.to_bytes()[..]);
+        let decrypted =
+            assembled.decrypt_data(&secret).expect("Decryption error");
+        assert_eq!(decrypted.value(), value);
+        assert!(assembled
+            .verify_commitment(&decrypted.value(), &decrypted.blinder()));
+    }
+
+    #[test]
+    fn decryption_failure() {
+        let (bid, _) = bid_with_value(JubJubScalar::from(V_RAW_MIN))
+            .expect("Bid creation error");
+        let wrong_secret: JubJubAffine =
+            (GENERATOR_EXTENDED * JubJubScalar::from(7u64)).into();
+        assert!(matches!(
+            bid.decrypt_data(&wrong_secret),
+            Err(BlindBidError::DecryptionFailed)
+        ));
+    }
+
+    #[test]
+    fn deterministic_construction() {
+        let pk_r = PublicSpendKey::from(SecretSpendKey::new(
+            JubJubScalar::one(),
+            -JubJubScalar::one(),
+        ));
+        let stealth_addr = pk_r.gen_stealth_address(&JubJubScalar::one());
+        let secret: JubJubAffine =
+            (GENERATOR_EXTENDED * JubJubScalar::from(7u64)).into();
+        let value = JubJubScalar::from(V_RAW_MIN);
+        let blinder = JubJubScalar::from(42u64);
+        let build = || {
+            Bid::new_deterministic(
+                &stealth_addr,
+                &value,
+                &secret,
+                BlsScalar::one(),
+                &blinder,
+                BlsScalar::from(3u64),
+                10,
+                20,
+            )
+            .expect("Bid creation error")
+        };
+
+        let bid = build();
+        assert_eq!(bid.to_bytes()[..], build().to_bytes()[..]);
+        assert_eq!(bid.nonce(), BlsScalar::from(3u64));
+        assert!(bid.verify_commitment(&value, &blinder));
+        let decrypted = bid.decrypt_data(&secret).expect("Decryption error");
+        assert_eq!(decrypted.value(), value);
+        assert_eq!(decrypted.blinder(), blinder);
+    }
+
+    #[test]
+    fn reencryption_under_a_new_secret() {
+        let mut rng = rand::thread_rng();
+        let (bid, old_secret) = bid_with_value(JubJubScalar::from(V_RAW_MIN))
+            .expect("Bid creation error");
+        let bid = bid.with_position(3);
+        let new_secret: JubJubAffine =
+            (GENERATOR_EXTENDED * JubJubScalar::random(&mut rng)).into();
+
+        let reencrypted = bid
+            .reencrypt(&old_secret, &new_secret, &mut rng)
+            .expect("Re-encryption error");
+        assert_eq!(reencrypted.commitment(), bid.commitment());
+        assert_eq!(reencrypted.hashed_secret(), bid.hashed_secret());
+        assert_eq!(reencrypted.eligibility(), bid.eligibility());
+        assert_eq!(reencrypted.expiration(), bid.expiration());
+        assert_eq!(reencrypted.pos(), bid.pos());
+
+        let old = bid.decrypt_data(&old_secret).expect("Decryption error");
+        let new = reencrypted
+            .decrypt_data(&new_secret)
+            .expect("Decryption error");
+        assert_eq!(new.value(), old.value());
+        assert_eq!(new.blinder(), old.blinder());
+        assert!(reencrypted.decrypt_data(&old_secret).is_err());
+
+        assert!(matches!(
+            bid.reencrypt(&new_secret, &old_secret, &mut rng),
+            Err(BlindBidError::DecryptionFailed)
+        ));
+    }
+
+    #[cfg(feature = "test-helpers")]
+    #[test]
+    fn random_bids_are_well_formed() {
+        let mut rng = rand::thread_rng();
+        let bid = Bid::random(&mut rng);
+        assert_eq!(bid.eligibility(), u64::MAX);
+        assert_eq!(bid.expiration(), u64::MAX);
+        assert_ne!(bid.hash(), Bid::random(&mut rng).hash());
+    }
+
+    #[test]
+    fn construction_with_blinder() {
+        let mut rng = rand::thread_rng();
+        let value = JubJubScalar::from(V_RAW_MIN);
+        let (template, secret) =
+            bid_with_value(value).expect("Bid creation error");
+        let stealth_addr = *template.stealth_address();
+        let blinder = JubJubScalar::from(42u64);
+        let build = |rng: &mut rand::rngs::ThreadRng| {
+            Bid::new_with_blinder(
+                rng,
+                &stealth_addr,
+                &value,
+                &blinder,
+                &secret,
+                BlsScalar::one(),
+                10,
+                20,
+            )
+            .expect("Bid creation error")
+        };
+
+        let bid = build(&mut rng);
+        assert_eq!(bid.commitment(), build(&mut rng).commitment());
+        assert!(bid.verify_commitment(&value, &blinder));
+        let deterministic = Bid::new_deterministic(
+            &stealth_addr,
+            &value,
+            &secret,
+            BlsScalar::one(),
+            &blinder,
+            bid.nonce(),
+            10,
+            20,
+        )
+        .expect("Bid creation error");
+        assert_eq!(bid.to_bytes()[..], deterministic.to_bytes()[..]);
+        assert_eq!(
+            bid.decrypt_data(&secret)
+                .expect("Decryption error")
+                .blinder(),
+            blinder
+        );
+    }
+
+    #[test]
+    fn commitment_generators() {
+        assert_eq!(value_generator(), GENERATOR_EXTENDED);
+        assert_eq!(blinder_generator(), GENERATOR_NUMS_EXTENDED);
+
+        let value = JubJubScalar::from(V_RAW_MIN);
+        let blinder = JubJubScalar::from(42u64);
+        let secret: JubJubAffine =
+            (GENERATOR_EXTENDED * JubJubScalar::from(7u64)).into();
+        let bid = Bid::new_deterministic(
+            &PublicSpendKey::from(SecretSpendKey::new(
+                JubJubScalar::one(),
+                -JubJubScalar::one(),
+            ))
+            .gen_stealth_address(&JubJubScalar::one()),
+            &value,
+            &secret,
+            BlsScalar::one(),
+            &blinder,
+            BlsScalar::from(3u64),
+            10,
+            20,
+        )
+        .expect("Bid creation error");
+
+        let c = JubJubAffine::from(
+            &(value_generator() * value) + &(blinder_generator() * blinder),
+        );
+        assert_eq!(bid.commitment(), c);
+        assert!(bid.verify_commitment(&value, &blinder));
+    }
+
+    #[test]
+    fn tiered_construction() {
+        let (template, secret) = bid_with_value(JubJubScalar::from(V_RAW_MIN))
+            .expect("Bid creation error");
+        let new_bid = |value: u64, tier| {
+            Bid::new_tiered(
+                &mut rand::thread_rng(),
+                template.stealth_address(),
+                &JubJubScalar::from(value),
+                &secret,
+                BlsScalar::one(),
+                10,
+                20,
+                tier,
+            )
+        };
+
+        let low = BidTier::Range {
+            min: 1_000,
+            max: 2_000,
+        };
+        let high = BidTier::Range {
+            min: 1_000_000,
+            max: 2_000_000,
+        };
+        for tier in [BidTier::Standard, low, high].iter() {
+            let (min, max) = tier.bounds();
+            assert!(new_bid(min, *tier).is_ok());
+            assert!(new_bid(max, *tier).is_ok());
+            assert!(matches!(
+                new_bid(min - 1, *tier),
+                Err(BlindBidError::MinimumBidValueUnreached { .. })
+            ));
+            assert!(matches!(
+                new_bid(max + 1, *tier),
+                Err(BlindBidError::MaximumBidValueExceeded { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn timestamps_as_field_elements() {
+        let (mut bid, _) = bid_with_value(JubJubScalar::from(V_RAW_MIN))
+            .expect("Bid creation error");
+        bid.set_eligibility(u64::MAX - 1);
+        bid.expiration = u64::MAX;
+
+        assert_eq!(bid.eligibility_scalar(), BlsScalar::from(u64::MAX - 1));
+        assert_eq!(bid.expiration_scalar(), BlsScalar::from(u64::MAX));
+        // The preimage of the hash, checked in-circuit against the same
+        // conversion, holds the timestamps at positions 10 and 11.
+        let inputs = bid.as_hash_inputs();
+        assert_eq!(inputs[10], bid.eligibility_scalar());
+        assert_eq!(inputs[11], bid.expiration_scalar());
+    }
+
+    #[test]
+    fn hashed_secret_from_secret_k() {
+        let secret_k = BlsScalar::random(&mut rand::thread_rng());
+        let (template, secret) = bid_with_value(JubJubScalar::from(V_RAW_MIN))
+            .expect("Bid creation error");
+        let bid = BidBuilder::new()
+            .stealth_address(*template.stealth_address())
+            .value(JubJubScalar::from(V_RAW_MIN))
+            .secret(secret)
+            .secret_k(secret_k)
+            .eligibility(10)
+            .expiration(20)
+            .build(&mut rand::thread_rng())
+            .expect("Bid creation error");
+
+        assert_eq!(bid.hashed_secret(), hash_secret_k(secret_k));
+        assert_ne!(bid.hashed_secret(), hash_secret_k(BlsScalar::one()));
+    }
+
+    #[test]
+    fn hashes_are_domain_separated() {
+        let secret_k = BlsScalar::random(&mut rand::thread_rng());
+        let (seed, round, step) = (secret_k, secret_k, secret_k);
+
+        assert_ne!(DOMAIN_HASHED_SECRET, DOMAIN_PROVER_ID);
+        assert_ne!(hash_secret_k(secret_k), DefaultHasher::hash(&[secret_k]));
+        assert_ne!(
+            compute_prover_id(secret_k, seed, round, step),
+            DefaultHasher::hash(&[secret_k, seed, round, step])
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "poseidon-constant-length"))]
+    fn domain_separated_test_vectors() {
+        // Pins the outputs of the tagged preimages, so any change to the
+        // tags or to their position in the preimage is caught.
+        assert_eq!(
+            hash_secret_k(BlsScalar::one()),
+            BlsScalar::from_raw([
+                0x99758dbd8ea718e8,
+                0x3be2cb5f227a9a68,
+                0x17b3ce62f923e234,
+                0x0fa7675be843a18d,
+            ])
+        );
+        assert_eq!(
+            compute_prover_id(
+                BlsScalar::one(),
+                BlsScalar::from(2u64),
+                BlsScalar::from(3u64),
+                BlsScalar::from(4u64),
+            ),
+            BlsScalar::from_raw([
+                0x28ec337add304bcc,
+                0xe6df50df0ce17e25,
+                0xc3c16b71c56753fb,
+                0x0b5d8e15ee2cbb0d,
+            ])
+        );
+    }
+
+    #[test]
+    fn bytes_getters() {
+        let (bid, _) = bid_with_value(JubJubScalar::from(V_RAW_MIN))
+            .expect("Bid creation error");
+        assert_eq!(bid.commitment_bytes(), bid.commitment().to_bytes());
+
+        let (secret_k, seed, round, step) = (
+            BlsScalar::one(),
+            BlsScalar::from(2u64),
+            BlsScalar::from(3u64),
+            BlsScalar::from(4u64),
+        );
+        assert_eq!(
+            bid.prover_id_bytes(secret_k, seed, round, step),
+            bid.generate_prover_id(secret_k, seed, round, step)
+                .to_bytes()
+        );
+    }
+
+    #[test]
+    fn equality_is_field_by_field() {
+        let (bid, _) = bid_with_value(JubJubScalar::from(V_RAW_MIN))
+            .expect("Bid creation error");
+        let mut other = bid;
+        assert_eq!(bid, other);
+
+        other.pos += 1;
+        assert_ne!(bid, other);
+
+        let mut other = bid;
+        other.set_metadata([1u8; 32]);
+        assert_ne!(bid, other);
+    }
+
+    #[test]
+    fn standalone_value_validation() {
+        assert!(Bid::validate_value(&JubJubScalar::from(V_RAW_MIN)).is_ok());
+        assert!(Bid::validate_value(&JubJubScalar::from(V_RAW_MAX)).is_ok());
+        assert!(matches!(
+            Bid::validate_value(&JubJubScalar::from(V_RAW_MIN - 1)),
+            Err(BlindBidError::MinimumBidValueUnreached { .. })
+        ));
+        assert!(matches!(
+            Bid::validate_value(&JubJubScalar::from(V_RAW_MAX + 1)),
+            Err(BlindBidError::MaximumBidValueExceeded { .. })
+        ));
+    }
+
+    #[test]
+    fn bid_values_batch_validation() {
+        let results = validate_bid_values(&[
+            JubJubScalar::from(V_RAW_MIN - 1),
+            JubJubScalar::from(V_RAW_MIN),
+            JubJubScalar::from(V_RAW_MAX + 1),
+        ]);
+
+        assert_eq!(results.len(), 3);
+        assert!(matches!(
+            results[0],
+            Err(BlindBidError::MinimumBidValueUnreached { .. })
+        ));
+        assert!(results[1].is_ok());
+        assert!(matches!(
+            results[2],
+            Err(BlindBidError::MaximumBidValueExceeded { .. })
+        ));
+    }
+
+    #[test]
+    fn weight_is_the_bid_value() {
+        let value: u64 = rand::thread_rng().gen_range(V_RAW_MIN, V_RAW_MAX);
+        let (bid, secret) = bid_with_value(JubJubScalar::from(value))
+            .expect("Bid creation error");
+
+        assert_eq!(bid.weight(&secret).expect("Decryption error"), value);
+    }
+
+    #[test]
+    fn metadata_is_not_consensus() {
+        let (bid, _) = bid_with_value(JubJubScalar::from(V_RAW_MIN))
+            .expect("Bid creation error");
+        let mut tagged_bid = bid;
+        tagged_bid.set_metadata([7u8; 32]);
+
+        assert_eq!(bid.hash(), tagged_bid.hash());
+        assert_eq!(bid.commitment(), tagged_bid.commitment());
+        assert_ne!(bid.to_bytes()[..], tagged_bid.to_bytes()[..]);
+        assert_eq!(
+            Bid::from_bytes(&tagged_bid.to_bytes())
+                .expect("Invalid roundtrip")
+                .metadata(),
+            &[7u8; 32]
+        );
     }
 }
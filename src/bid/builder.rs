@@ -0,0 +1,219 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Builder used to construct [Bid](super::Bid)s field by field.
+
+use super::Bid;
+use crate::errors::BlindBidError;
+use dusk_bls12_381::BlsScalar;
+use dusk_jubjub::{JubJubAffine, JubJubScalar};
+use dusk_pki::StealthAddress;
+use rand_core::{CryptoRng, RngCore};
+
+/// Builder for [Bid]s.
+///
+/// All of the fields are required. [`BidBuilder::build`] returns a
+/// [`BlindBidError::MissingField`] naming the first one that wasn't set.
+///
+/// The value is checked against the `[V_MIN, V_MAX]` range unless custom
+/// bounds are set with [`BidBuilder::bounds`].
+///
+/// # Example
+/// ```ignore
+/// let bid = BidBuilder::new()
+///     .stealth_address(stealth_addr)
+///     .value(JubJubScalar::from(V_RAW_MIN))
+///     .secret(secret)
+///     .secret_k(secret_k)
+///     .eligibility(eligibility_ts)
+///     .expiration(expiration_ts)
+///     .build(&mut rng)?;
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BidBuilder {
+    stealth_address: Option<StealthAddress>,
+    value: Option<JubJubScalar>,
+    secret: Option<JubJubAffine>,
+    secret_k: Option<BlsScalar>,
+    eligibility: Option<u64>,
+    expiration: Option<u64>,
+    bounds: Option<(JubJubScalar, JubJubScalar)>,
+}
+
+impl BidBuilder {
+    /// Creates a new builder with none of the fields set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the stealth address of the bidder.
+    pub fn stealth_address(mut self, stealth_address: StealthAddress) -> Self {
+        self.stealth_address = Some(stealth_address);
+        self
+    }
+
+    /// Sets the amount of Dusk bid.
+    pub fn value(mut self, value: JubJubScalar) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// Sets the secret used to encrypt the value and the blinder.
+    pub fn secret(mut self, secret: JubJubAffine) -> Self {
+        self.secret = Some(secret);
+        self
+    }
+
+    /// Sets the secret `k` whose hash is stored in the Bid.
+    pub fn secret_k(mut self, secret_k: BlsScalar) -> Self {
+        self.secret_k = Some(secret_k);
+        self
+    }
+
+    /// Sets the eligibility height of the Bid.
+    pub fn eligibility(mut self, eligibility: u64) -> Self {
+        self.eligibility = Some(eligibility);
+        self
+    }
+
+    /// Sets the expiration height of the Bid.
+    pub fn expiration(mut self, expiration: u64) -> Self {
+        self.expiration = Some(expiration);
+        self
+    }
+
+    /// Sets the `[min, max]` range the value is checked against.
+    pub fn bounds(mut self, min: JubJubScalar, max: JubJubScalar) -> Self {
+        self.bounds = Some((min, max));
+        self
+    }
+
+    /// Validates the fields and generates the [Bid].
+    pub fn build<R>(self, rng: &mut R) -> Result<Bid, BlindBidError>
+    where
+        R: RngCore + CryptoRng,
+    {
+        let stealth_address = self
+            .stealth_address
+            .ok_or(BlindBidError::MissingField("stealth_address"))?;
+        let value = self.value.ok_or(BlindBidError::MissingField("value"))?;
+        let secret =
+            self.secret.ok_or(BlindBidError::MissingField("secret"))?;
+        let secret_k = self
+            .secret_k
+            .ok_or(BlindBidError::MissingField("secret_k"))?;
+        let eligibility = self
+            .eligibility
+            .ok_or(BlindBidError::MissingField("eligibility"))?;
+        let expiration = self
+            .expiration
+            .ok_or(BlindBidError::MissingField("expiration"))?;
+
+        // Check if the bid_value is in the correct range, otherways, fail.
+        let (min, max) = self.bounds.unwrap_or((crate::V_MIN, crate::V_MAX));
+        Bid::validate_value_with_bounds(&value, &min, &max)?;
+        Bid::validate_timestamps(eligibility, expiration)?;
+        // Generate an empty Bid and fill it with the correct values
+        let mut bid =
+            Bid::unset(stealth_address, secret_k, eligibility, expiration);
+        bid.set_value(rng, &value, &secret, secret_k);
+
+        Ok(bid)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::V_RAW_MIN;
+    use dusk_jubjub::GENERATOR_EXTENDED;
+    use dusk_pki::{PublicSpendKey, SecretSpendKey};
+
+    fn full_builder() -> (BidBuilder, JubJubAffine) {
+        let mut rng = rand::thread_rng();
+        let pk_r = PublicSpendKey::from(SecretSpendKey::random(&mut rng));
+        let secret = JubJubScalar::random(&mut rng);
+        let stealth_addr = pk_r.gen_stealth_address(&secret);
+        let secret: JubJubAffine = (GENERATOR_EXTENDED * secret).into();
+
+        let builder = BidBuilder::new()
+            .stealth_address(stealth_addr)
+            .value(JubJubScalar::from(V_RAW_MIN))
+            .secret(secret)
+            .secret_k(BlsScalar::one())
+            .eligibility(10)
+            .expiration(20);
+        (builder, secret)
+    }
+
+    #[test]
+    fn build_sets_all_fields() {
+        let (builder, secret) = full_builder();
+        let bid = builder
+            .build(&mut rand::thread_rng())
+            .expect("Bid creation error");
+
+        assert_eq!(bid.eligibility(), 10);
+        assert_eq!(bid.expiration(), 20);
+        assert_eq!(
+            bid.decrypt_data(&secret).expect("Decryption error").value(),
+            JubJubScalar::from(V_RAW_MIN)
+        );
+    }
+
+    #[test]
+    fn build_reports_missing_fields() {
+        let (builder, _) = full_builder();
+        let mut missing = builder;
+        missing.expiration = None;
+        assert!(matches!(
+            missing.build(&mut rand::thread_rng()),
+            Err(BlindBidError::MissingField("expiration"))
+        ));
+
+        assert!(matches!(
+            BidBuilder::new().build(&mut rand::thread_rng()),
+            Err(BlindBidError::MissingField("stealth_address"))
+        ));
+    }
+
+    #[test]
+    fn build_checks_value_range() {
+        let (builder, _) = full_builder();
+        assert!(matches!(
+            builder
+                .value(JubJubScalar::from(V_RAW_MIN - 1))
+                .build(&mut rand::thread_rng()),
+            Err(BlindBidError::MinimumBidValueUnreached { .. })
+        ));
+    }
+
+    #[test]
+    fn build_checks_custom_bounds() {
+        let (builder, _) = full_builder();
+        let builder = builder
+            .bounds(JubJubScalar::from(1_000u64), JubJubScalar::from(2_000u64));
+
+        assert!(builder
+            .value(JubJubScalar::from(1_000u64))
+            .build(&mut rand::thread_rng())
+            .is_ok());
+        assert!(matches!(
+            builder
+                .value(JubJubScalar::from(999u64))
+                .build(&mut rand::thread_rng()),
+            Err(BlindBidError::MinimumBidValueUnreached { .. })
+        ));
+        // Within the default range but above the custom maximum.
+        assert!(matches!(
+            builder
+                .value(JubJubScalar::from(V_RAW_MIN))
+                .build(&mut rand::thread_rng()),
+            Err(BlindBidError::MaximumBidValueExceeded { .. })
+        ));
+    }
+}
@@ -7,14 +7,14 @@
 //! Encoding module for Bid structure.
 //! See: <https://hackmd.io/@7dpNYqjKQGeYC7wMlPxHtQ/BkfS78Y9L>
 
-use super::Bid;
+use super::{timestamp_to_scalar, Bid};
+use crate::hasher::{BidHasher, DefaultHasher};
 use dusk_bls12_381::BlsScalar;
 use dusk_bytes::Serializable;
-#[cfg(feature = "std")]
+#[cfg(feature = "proof")]
 use dusk_plonk::constraint_system::ecc::Point as PlonkPoint;
-#[cfg(feature = "std")]
+#[cfg(feature = "proof")]
 use dusk_plonk::prelude::*;
-use dusk_poseidon::sponge;
 
 // 1. Generate the type_fields Scalar Id:
 // Type 1 will be BlsScalar
@@ -67,21 +67,45 @@ impl Bid {
         words_deposit[8] = self.c.get_x();
         words_deposit[9] = self.c.get_y();
         // Push the timestamps of the Bid
-        words_deposit[10] = BlsScalar::from(self.eligibility);
-        words_deposit[11] = BlsScalar::from(self.expiration);
+        words_deposit[10] = timestamp_to_scalar(self.eligibility);
+        words_deposit[11] = timestamp_to_scalar(self.expiration);
         words_deposit[12] = BlsScalar::from(self.pos);
 
         words_deposit
     }
 
-    /// Calculate the one-way BlsScalar representation of the Bid
+    /// Calculate the one-way BlsScalar representation of the Bid, which is
+    /// used as its identifier inside of the Bid tree.
+    ///
+    /// The hash is the Poseidon sponge hash of the following 13 words, **in
+    /// order** (see [`Bid::as_hash_inputs`]):
+    /// 0. The type flags `b"53313116000000000000000000000000"` read as a
+    ///    little-endian `BlsScalar`.
+    /// 1. - 2. The first two scalars of the encrypted data cipher.
+    /// 3. - 4. The `x` and `y` coordinates of the `pk_r` of the stealth
+    ///    address.
+    /// 5. - 6. The `x` and `y` coordinates of the `R` of the stealth address.
+    /// 7. The hashed secret.
+    /// 8. - 9. The `x` and `y` coordinates of the commitment.
+    /// 10. The eligibility, encoded with [`timestamp_to_scalar`].
+    /// 11. The expiration, encoded with [`timestamp_to_scalar`].
+    /// 12. The position of the Bid in the tree, as a `BlsScalar`.
+    ///
+    /// Neither the nonce nor the metadata of the Bid are part of the hash.
+    /// This layout is stable: changing it changes the identifiers of all of
+    /// the Bids.
+    ///
+    /// The words are held in a fixed-size array, so hashing a Bid doesn't
+    /// allocate. Each leaf of the Bid tree is the hash of a single Bid, so
+    /// the Poseidon state can't be shared between the Bids of a tree: bulk
+    /// insertions should hash every Bid with this method.
     pub fn hash(&self) -> BlsScalar {
         // Set the Bid parameters on a "hasheable" way to be digested
         // by the poseidon sponge hash.
         // Once all of the words are translated as `Scalar` and stored
         // correctly, apply the Poseidon sponge hash function to obtain
         // the encoded form of the `Bid`.
-        sponge::hash(&self.as_hash_inputs())
+        DefaultHasher::hash(&self.as_hash_inputs())
     }
 }
 
@@ -100,8 +124,8 @@ impl Into<BlsScalar> for Bid {
 /// Hashes the internal Bid parameters using the Poseidon hash
 /// function and the cannonical encoding for hashing returning a
 /// Variable which contains the hash of the Bid.
-#[cfg(feature = "std")]
-#[cfg_attr(docsrs, doc(cfg(feature = "canon")))]
+#[cfg(feature = "proof")]
+#[cfg_attr(docsrs, doc(cfg(feature = "proof")))]
 pub(crate) fn preimage_gadget(
     composer: &mut StandardComposer,
     // TODO: We should switch to a different representation for this.
@@ -146,10 +170,10 @@ pub(crate) fn preimage_gadget(
     messages.push(pos);
 
     // Perform the sponge_hash inside of the Constraint System
-    sponge::gadget(composer, &messages)
+    DefaultHasher::gadget(composer, &messages)
 }
 
-#[cfg(feature = "std")]
+#[cfg(feature = "proof")]
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -266,3 +290,74 @@ mod tests {
         verifier.verify(&proof, &vk, &pi)
     }
 }
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod preimage_tests {
+    use super::*;
+    use dusk_bytes::Serializable;
+    use dusk_jubjub::GENERATOR;
+    use dusk_pki::StealthAddress;
+    use dusk_poseidon::cipher::PoseidonCipher;
+
+    /// Bid with fixed fields whose points are all the JubJub generator, so
+    /// its hash can be computed independently of this crate.
+    fn fixed_bid() -> Bid {
+        let mut stealth_address = [0u8; StealthAddress::SIZE];
+        stealth_address[..32].copy_from_slice(&GENERATOR.to_bytes());
+        stealth_address[32..].copy_from_slice(&GENERATOR.to_bytes());
+        let stealth_address = StealthAddress::from_bytes(&stealth_address)
+            .expect("Invalid stealth address");
+
+        Bid::from_parts(
+            PoseidonCipher::new([
+                BlsScalar::from(1u64),
+                BlsScalar::from(2u64),
+                BlsScalar::from(3u64),
+            ]),
+            BlsScalar::from(4u64),
+            stealth_address,
+            BlsScalar::from(5u64),
+            GENERATOR,
+            6,
+            7,
+            8,
+        )
+    }
+
+    #[test]
+    fn hash_preimage_layout() {
+        let bid = fixed_bid();
+        let expected = [
+            BlsScalar::from_bytes(b"53313116000000000000000000000000")
+                .expect("Invalid type flags"),
+            BlsScalar::from(1u64),
+            BlsScalar::from(2u64),
+            GENERATOR.get_x(),
+            GENERATOR.get_y(),
+            GENERATOR.get_x(),
+            GENERATOR.get_y(),
+            BlsScalar::from(5u64),
+            GENERATOR.get_x(),
+            GENERATOR.get_y(),
+            BlsScalar::from(6u64),
+            BlsScalar::from(7u64),
+            BlsScalar::from(8u64),
+        ];
+        assert_eq!(bid.as_hash_inputs(), expected);
+    }
+
+    #[test]
+    #[cfg(not(feature = "poseidon-constant-length"))]
+    fn hash_test_vector() {
+        // Computed with an independent implementation of the Poseidon
+        // sponge over the preimage of `hash_preimage_layout`.
+        let expected = BlsScalar::from_raw([
+            0x8253407d5681ae19,
+            0x2504fa0f408378f6,
+            0xfec9c72889e4cd18,
+            0x19dc288b06dcb942,
+        ]);
+        assert_eq!(fixed_bid().hash(), expected);
+    }
+}
@@ -17,21 +17,30 @@ cfg_if::cfg_if! {
     if #[cfg(feature = "std")] {
         use crate::errors::BlindBidError;
         use crate::bid::Bid;
-        use dusk_jubjub::JubJubAffine;
-        use dusk_plonk::prelude::*;
+        use dusk_jubjub::{JubJubAffine, JubJubScalar};
         use num_bigint::BigUint;
-        use num_traits::{One, Zero};
-        use dusk_poseidon::sponge;use plonk_gadgets::{
+        use num_traits::Zero;
+        use dusk_poseidon::sponge;
+    }
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "proof")] {
+        use dusk_plonk::prelude::*;
+        use plonk_gadgets::{
             AllocatedScalar, RangeGadgets::max_bound, ScalarGadgets::maybe_equal,
-};
+        };
     }
 }
 
+use core::cmp::Ordering;
 use core::ops::Deref;
+#[cfg(feature = "std")]
+use core::ops::RangeInclusive;
 use dusk_bls12_381::BlsScalar;
 use dusk_bytes::{DeserializableSlice, Serializable};
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Default)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "canon", derive(Canon))]
 /// The `Score` represents a "random" value obtained from the computations
 /// based on blockchain data as well as [Bid](self::Bid) data.
@@ -46,6 +55,24 @@ pub struct Score {
     r2: BlsScalar,
 }
 
+impl Default for Score {
+    /// Returns an all-zero Score.
+    ///
+    /// It is only meant to be used as a placeholder for the verifier side of
+    /// the [BlindBidCircuit](crate::BlindBidCircuit), where the real Score is
+    /// a Public Input. It is never a legitimate Score: see
+    /// [`Score::is_placeholder`].
+    fn default() -> Self {
+        Score {
+            value: BlsScalar::zero(),
+            y: BlsScalar::zero(),
+            y_prime: BlsScalar::zero(),
+            r1: BlsScalar::zero(),
+            r2: BlsScalar::zero(),
+        }
+    }
+}
+
 impl Deref for Score {
     type Target = BlsScalar;
     fn deref(&self) -> &Self::Target {
@@ -59,7 +86,15 @@ impl AsRef<BlsScalar> for Score {
     }
 }
 
-impl Serializable<{ 5 * BlsScalar::SIZE }> for Score {
+/// Length of the [Serializable] representation of a [`Score`].
+///
+/// The Score is serialized as its `value`, `y`, `y'`, `r1` and `r2` fields in
+/// that order, each one as a canonical little-endian [`BlsScalar`], which is
+/// everything needed to rebuild it for the
+/// [BlindBidCircuit](crate::BlindBidCircuit) or [`Score::verify`].
+pub const SCORE_SERIALIZED_SIZE: usize = 5 * BlsScalar::SIZE;
+
+impl Serializable<SCORE_SERIALIZED_SIZE> for Score {
     type Error = dusk_bytes::Error;
 
     #[allow(unused_must_use)]
@@ -68,11 +103,11 @@ impl Serializable<{ 5 * BlsScalar::SIZE }> for Score {
 
         let mut buf = [0u8; Self::SIZE];
         let mut writer = &mut buf[..];
-        writer.write(&self.as_ref().to_bytes());
-        writer.write(&self.as_ref().to_bytes());
-        writer.write(&self.as_ref().to_bytes());
-        writer.write(&self.as_ref().to_bytes());
-        writer.write(&self.as_ref().to_bytes());
+        writer.write(&self.value.to_bytes());
+        writer.write(&self.y.to_bytes());
+        writer.write(&self.y_prime.to_bytes());
+        writer.write(&self.r1.to_bytes());
+        writer.write(&self.r2.to_bytes());
         buf
     }
 
@@ -92,13 +127,85 @@ impl Serializable<{ 5 * BlsScalar::SIZE }> for Score {
 }
 
 impl Score {
+    /// Assembles a Score from already computed fields, e.g. by a
+    /// [`ScoreFunction`] other than the default one.
+    ///
+    /// No check is performed: see [`Score::verify`] for the relationships
+    /// that the fields of a Score computed with the default function hold.
+    pub const fn from_parts(
+        value: BlsScalar,
+        y: BlsScalar,
+        y_prime: BlsScalar,
+        r1: BlsScalar,
+        r2: BlsScalar,
+    ) -> Score {
+        Score {
+            value,
+            y,
+            y_prime,
+            r1,
+            r2,
+        }
+    }
+
     /// Returns the value of the [Score](self::Score)
     pub fn value(&self) -> BlsScalar {
         self.value
     }
+
+    /// Returns `true` if the value of the Score is zero, as for
+    /// [`Score::default`].
+    ///
+    /// Such a Score can't be a legitimate one, since the computation of the
+    /// Score rejects zero values with [`DegenerateScore`].
+    ///
+    /// [`DegenerateScore`]: crate::BlindBidError::DegenerateScore
+    pub fn is_placeholder(&self) -> bool {
+        self.value == BlsScalar::zero()
+    }
+
+    /// Compares the Score with `other` following the winner-selection rule of
+    /// the protocol: the greatest Score wins.
+    ///
+    /// Scores are compared by the integers their values represent, which is
+    /// not the ordering of their Montgomery form. Scores with the same value
+    /// compare as [`Ordering::Equal`] regardless of the rest of their
+    /// fields, so breaking ties is left to the consensus.
+    pub fn cmp_consensus(&self, other: &Score) -> Ordering {
+        self.value
+            .to_bytes()
+            .iter()
+            .rev()
+            .cmp(other.value.to_bytes().iter().rev())
+    }
+
+    /// Returns `y = H(k, root, seed, round, step)`, which depends on the
+    /// `secret_k` of the Bid, the root of the Bid tree and all of the
+    /// consensus inputs (round seed, latest round and latest step).
+    pub fn y(&self) -> BlsScalar {
+        self.y
+    }
+
+    /// Returns `y'`, the lowest 128 bits of [`Score::y`] and therefore
+    /// depending on the same inputs.
+    pub fn y_prime(&self) -> BlsScalar {
+        self.y_prime
+    }
+
+    /// Returns `r1`, the highest 128 bits of [`Score::y`], so that
+    /// `y = r1 * 2^128 + y'`.
+    pub fn r1(&self) -> BlsScalar {
+        self.r1
+    }
+
+    /// Returns `r2`, the remainder of the division `value * 2^128 / y'` that
+    /// yields the score, which also depends on the value of the Bid.
+    pub fn r2(&self) -> BlsScalar {
+        self.r2
+    }
 }
 
-#[cfg(feature = "std")]
+#[cfg(feature = "proof")]
 pub(self) const SCALAR_FIELD_ORD_DIV_2_POW_128: BlsScalar =
     BlsScalar::from_raw([
         0x3339d80809a1d805,
@@ -107,7 +214,7 @@ pub(self) const SCALAR_FIELD_ORD_DIV_2_POW_128: BlsScalar =
         0x0000000000000000,
     ]);
 
-#[cfg(feature = "std")]
+#[cfg(feature = "proof")]
 pub(self) const MINUS_ONE_MOD_2_POW_128: BlsScalar = BlsScalar::from_raw([
     0xffffffff00000000,
     0x53bda402fffe5bfe,
@@ -118,7 +225,25 @@ pub(self) const MINUS_ONE_MOD_2_POW_128: BlsScalar = BlsScalar::from_raw([
 #[cfg(feature = "std")]
 impl Score {
     /// Given a `Bid`, compute it's Score and return it.
-    #[cfg_attr(docsrs, doc(cfg(feature = "canon")))]
+    ///
+    /// A Score is only computed if the Bid can be proven at
+    /// `latest_consensus_round`: [`BlindBidError::ExpiredBid`] is returned if
+    /// the Bid [is expired](Bid::is_expired) and
+    /// [`BlindBidError::NonEligibleBid`] if it [is not
+    /// eligible](Bid::is_eligible).
+    ///
+    /// Since `y'` is always lower than `2^128`, the score of a Bid is always
+    /// greater or equal than its value. Therefore, a zero score is impossible
+    /// for a Bid with a value inside of the `[V_MIN, V_MAX]` range, and
+    /// [`BlindBidError::DegenerateScore`] is returned if that's ever the case
+    /// (e.g. a Bid whose value is zero).
+    ///
+    /// The consensus round and step are converted into field elements
+    /// without any `u64` arithmetic, so they can't overflow. The score
+    /// fields can only exceed the scalar field for values far greater than
+    /// `V_MAX`, in which case [`BlindBidError::ArithmeticOverflow`] is
+    /// returned instead of a silently reduced score.
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
     pub fn compute(
         bid: &Bid,
         secret: &JubJubAffine,
@@ -128,60 +253,279 @@ impl Score {
         latest_consensus_round: u64,
         latest_consensus_step: u64,
     ) -> Result<Score, BlindBidError> {
-        if latest_consensus_round > bid.expiration {
+        Score::compute_with::<DefaultScoreFunction>(
+            bid,
+            secret,
+            secret_k,
+            bid_tree_root,
+            consensus_round_seed,
+            latest_consensus_round,
+            latest_consensus_step,
+        )
+    }
+
+    /// Same as [`Score::compute`] but deriving the Score from `y` and the
+    /// value of the Bid with the [`ScoreFunction`] `F`.
+    ///
+    /// Only the Scores of the [`DefaultScoreFunction`] can be proven with
+    /// the [BlindBidCircuit](crate::BlindBidCircuit).
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn compute_with<F: ScoreFunction>(
+        bid: &Bid,
+        secret: &JubJubAffine,
+        secret_k: BlsScalar,
+        bid_tree_root: BlsScalar,
+        consensus_round_seed: BlsScalar,
+        latest_consensus_round: u64,
+        latest_consensus_step: u64,
+    ) -> Result<Score, BlindBidError> {
+        // The timestamps are checked before decrypting the Bid, which is the
+        // most expensive part of the preparation.
+        if bid.is_expired(latest_consensus_round) {
             return Err(BlindBidError::ExpiredBid);
-        };
+        }
+        if !bid.is_eligible(latest_consensus_round) {
+            return Err(BlindBidError::NonEligibleBid);
+        }
 
-        let latest_consensus_round = BlsScalar::from(latest_consensus_round);
-        let latest_consensus_step = BlsScalar::from(latest_consensus_step);
+        Score::prepare(bid, secret, secret_k, bid_tree_root)?.score_with::<F>(
+            consensus_round_seed,
+            latest_consensus_round,
+            latest_consensus_step,
+        )
+    }
 
-        // Compute `y` where `y = H(secret_k, Merkle_root, consensus_round_seed,
-        // latest_consensus_round, latest_consensus_step)`.
-        let y = sponge::hash(&[
+    /// Returns the greatest score that a Bid of value `value` can obtain:
+    /// `value * 2^128`.
+    ///
+    /// The score is `value * 2^128 / y'`, where `y'` is the lowest 128 bits
+    /// of `y = H(secret_k, root, seed, round, step)`. The blinder of the Bid
+    /// plays no role in it and, since `secret_k` is chosen by the bidder,
+    /// `y'` can take any value for any root, seed, round and step. So the
+    /// bound is reached for `y'` equal to 0 or 1 and doesn't depend on the
+    /// consensus inputs. It is an upper bound for all of the Bids of the
+    /// same value, not the expected score of any of them.
+    ///
+    /// The product is computed in the scalar field, so the bound is only
+    /// meaningful for values lower than `2^126`, which all of the values in
+    /// the `[V_MIN, V_MAX]` range are.
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn max_for_value(value: &JubJubScalar) -> BlsScalar {
+        BlsScalar::from(*value) * BlsScalar::from(2u64).pow(&[128, 0, 0, 0])
+    }
+
+    /// Same as [`Score::compute`] but also returning the [`ScoreContext`] of
+    /// the consensus round and step the Score was computed for.
+    ///
+    /// The Score itself doesn't store them, since they are already part of
+    /// the Public Inputs of the proof it is sent with.
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn compute_with_context(
+        bid: &Bid,
+        secret: &JubJubAffine,
+        secret_k: BlsScalar,
+        bid_tree_root: BlsScalar,
+        consensus_round_seed: BlsScalar,
+        latest_consensus_round: u64,
+        latest_consensus_step: u64,
+    ) -> Result<(Score, ScoreContext), BlindBidError> {
+        let score = Score::compute(
+            bid,
+            secret,
             secret_k,
             bid_tree_root,
             consensus_round_seed,
             latest_consensus_round,
             latest_consensus_step,
-        ]);
-        let (value, _) = bid.decrypt_data(secret)?;
+        )?;
+        let context = ScoreContext::new(
+            consensus_round_seed,
+            latest_consensus_round,
+            latest_consensus_step,
+        );
+        Ok((score, context))
+    }
 
-        // Truncate Y to left 128 bits and interpret the result as 128-bit
-        // integer. Keep the right 128 bits as another integer (r1).
-        let r1 = BigUint::from_bytes_le(&y.to_bytes()[16..32]);
-        let y_prime = BigUint::from_bytes_le(&y.to_bytes()[0..16]);
+    /// Same as [`Score::compute`] but also returning the [`ScoreTrace`] of
+    /// the witness values that the score gadget of the
+    /// [BlindBidCircuit](crate::BlindBidCircuit) assigns for the Score.
+    #[cfg(feature = "debug-trace")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "debug-trace")))]
+    pub fn compute_with_trace(
+        bid: &Bid,
+        secret: &JubJubAffine,
+        secret_k: BlsScalar,
+        bid_tree_root: BlsScalar,
+        consensus_round_seed: BlsScalar,
+        latest_consensus_round: u64,
+        latest_consensus_step: u64,
+    ) -> Result<(Score, ScoreTrace), BlindBidError> {
+        let score = Score::compute(
+            bid,
+            secret,
+            secret_k,
+            bid_tree_root,
+            consensus_round_seed,
+            latest_consensus_round,
+            latest_consensus_step,
+        )?;
+        let bid_value: BlsScalar = bid.decrypt_data(secret)?.value().into();
+        let two_pow_128 = BlsScalar::from(2u64).pow(&[128, 0, 0, 0]);
+        let score_times_y_prime = score.value * score.y_prime;
 
-        // Get the bid value outside of the modular field and treat it as
-        // an integer.
-        let bid_value = BigUint::from_bytes_le(&value.to_bytes());
-        // Compute the final score
-        let (f, r2) = match y_prime == BigUint::zero() {
-            // If y' != 0 -> f = (bid_value * 2^128 / y')
-            // r2 is assigned to the remainder of the division.
-            false => {
-                let num = bid_value * (BigUint::one() << 128);
-                (&num / &y_prime, &num % &y_prime)
-            }
-            // If y' == 0 -> f = bid_value * 2^128
-            // Since there's not any division, r2 is assigned to 0 since
-            // there's not any remainder.
-            true => (bid_value * (BigUint::one() << 128), BigUint::zero()),
+        let trace = ScoreTrace {
+            hash_inputs: [
+                secret_k,
+                bid_tree_root,
+                consensus_round_seed,
+                BlsScalar::from(latest_consensus_round),
+                BlsScalar::from(latest_consensus_step),
+            ],
+            y: score.y,
+            y_prime: score.y_prime,
+            r1: score.r1,
+            r2: score.r2,
+            score: score.value,
+            bid_value,
+            recomposed_y: score.r1 * two_pow_128 + score.y_prime,
+            r2_minus_y_prime: score.r2 - score.y_prime,
+            score_times_y_prime,
+            numerator: score_times_y_prime + score.r2,
+            value_times_2_pow_128: bid_value * two_pow_128,
         };
+        Ok((score, trace))
+    }
 
-        // Get Scalars from the bigUints and return a `Score` if the conversions
-        // could be correctly done.
-        Ok(Score {
-            value: biguint_to_scalar(f)?,
-            y,
-            y_prime: biguint_to_scalar(y_prime)?,
-            r1: biguint_to_scalar(r1)?,
-            r2: biguint_to_scalar(r2)?,
+    /// Computes the Score of a `Bid` for each one of the consensus `rounds`
+    /// at the same `latest_consensus_step`, returning them in order.
+    ///
+    /// The rounds where the Bid is expired or not eligible yield the same
+    /// errors as [`Score::compute`] does for them.
+    ///
+    /// The Bid is decrypted only once through [`Score::prepare`], so each
+    /// round only costs a Poseidon hash and the [`ScoreFunction`].
+    /// The results of all of the rounds are held in memory, so callers
+    /// scanning large ranges might prefer to iterate over
+    /// [`ScorePreimage::score`] instead.
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn compute_range(
+        bid: &Bid,
+        secret: &JubJubAffine,
+        secret_k: BlsScalar,
+        bid_tree_root: BlsScalar,
+        consensus_round_seed: BlsScalar,
+        rounds: RangeInclusive<u64>,
+        latest_consensus_step: u64,
+    ) -> Vec<Result<Score, BlindBidError>> {
+        // The preparation can only fail if the Bid can't be decrypted.
+        let preimage = Score::prepare(bid, secret, secret_k, bid_tree_root);
+
+        rounds
+            .map(|round| match &preimage {
+                Ok(preimage) => preimage.score(
+                    consensus_round_seed,
+                    round,
+                    latest_consensus_step,
+                ),
+                Err(_) => Err(BlindBidError::DecryptionFailed),
+            })
+            .collect()
+    }
+
+    /// Checks the consistency of the Score of `bid` at `latest_consensus_round`
+    /// without recomputing it.
+    ///
+    /// The publicly checkable relationships are:
+    /// - The Bid is eligible and not expired at `latest_consensus_round`.
+    /// - The score is not zero.
+    /// - `y'` and `r1` are the lowest and highest 128 bits of `y`.
+    /// - `r2 < y'` (or `r2 = 0` if `y' = 0`), and `score * y' + r2` is a
+    ///   multiple of `2^128` whose quotient lies in the `[V_MIN, V_MAX]`
+    ///   range.
+    ///
+    /// On the other hand, `y = H(secret_k, root, seed, round, step)` can't be
+    /// recomputed without `secret_k`, so the Bid tree root, the consensus
+    /// round seed and step, and the relation between the quotient and the
+    /// commitment of the Bid are only bound by the proof of the
+    /// [BlindBidCircuit](crate::BlindBidCircuit). Since the quotient is the
+    /// value of the Bid, only the Score value is meant to be shared, and
+    /// nodes that hold the full Score are holding the value of the Bid.
+    ///
+    /// Fails with [`BlindBidError::InconsistentScore`] if any of the
+    /// relationships doesn't hold.
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn verify(
+        &self,
+        bid: &Bid,
+        latest_consensus_round: u64,
+    ) -> Result<(), BlindBidError> {
+        if bid.is_expired(latest_consensus_round) {
+            return Err(BlindBidError::ExpiredBid);
+        }
+        if !bid.is_eligible(latest_consensus_round) {
+            return Err(BlindBidError::NonEligibleBid);
+        }
+        if self.is_placeholder() {
+            return Err(BlindBidError::DegenerateScore);
+        }
+
+        let to_biguint = |s: &BlsScalar| BigUint::from_bytes_le(&s.to_bytes());
+        let (y, y_prime, r1, r2) = (
+            to_biguint(&self.y),
+            to_biguint(&self.y_prime),
+            to_biguint(&self.r1),
+            to_biguint(&self.r2),
+        );
+        let two_pow_128 = BigUint::from(1u32) << 128;
+        let split = y_prime < two_pow_128
+            && r1 < two_pow_128
+            && y == (&r1 << 128) + &y_prime;
+        let remainder = match y_prime == BigUint::zero() {
+            false => r2 < y_prime,
+            true => r2 == BigUint::zero(),
+        };
+        let numerator = match y_prime == BigUint::zero() {
+            false => to_biguint(&self.value) * &y_prime + &r2,
+            true => to_biguint(&self.value),
+        };
+        let value = &numerator >> 128;
+        let in_range = (&value << 128) == numerator
+            && value >= BigUint::from(crate::V_RAW_MIN)
+            && value <= BigUint::from(crate::V_RAW_MAX);
+
+        if !(split && remainder && in_range) {
+            return Err(BlindBidError::InconsistentScore);
+        }
+        Ok(())
+    }
+
+    /// Prepares the computation of the Scores of a `Bid` for several
+    /// consensus rounds and steps of the same Bid tree.
+    ///
+    /// See [`ScorePreimage`] for the computations that are shared by all of
+    /// the Scores.
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn prepare(
+        bid: &Bid,
+        secret: &JubJubAffine,
+        secret_k: BlsScalar,
+        bid_tree_root: BlsScalar,
+    ) -> Result<ScorePreimage, BlindBidError> {
+        let value = bid.decrypt_data(secret)?.value();
+
+        Ok(ScorePreimage {
+            secret_k,
+            bid_tree_root,
+            eligibility: bid.eligibility,
+            expiration: bid.expiration,
+            value,
         })
     }
 
     /// Proves that a `Score` is correctly generated.
     /// Prints the proving statements in the passed Constraint System.
-    #[cfg_attr(docsrs, doc(cfg(feature = "canon")))]
+    #[cfg(feature = "proof")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "proof")))]
     pub fn prove_correct_score_gadget(
         &self,
         composer: &mut StandardComposer,
@@ -361,6 +705,217 @@ impl Score {
     }
 }
 
+/// The ScoreContext holds the consensus round seed, round and step a
+/// [`Score`] was computed for, so the Score can be matched with the right
+/// consensus context.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScoreContext {
+    seed: BlsScalar,
+    round: u64,
+    step: u64,
+}
+
+impl ScoreContext {
+    /// Creates the context of the consensus round and step determinated by
+    /// `seed`, `round` and `step`.
+    pub const fn new(seed: BlsScalar, round: u64, step: u64) -> Self {
+        ScoreContext { seed, round, step }
+    }
+
+    /// Returns the consensus round seed.
+    pub fn seed(&self) -> BlsScalar {
+        self.seed
+    }
+
+    /// Returns the latest consensus round.
+    pub fn round(&self) -> u64 {
+        self.round
+    }
+
+    /// Returns the latest consensus step.
+    pub fn step(&self) -> u64 {
+        self.step
+    }
+}
+
+/// The ScoreTrace holds the field elements that the score gadget of the
+/// [BlindBidCircuit](crate::BlindBidCircuit) assigns for a [`Score`], in the
+/// order of its constraints, so they can be compared against the assignments
+/// of a circuit that fails to verify.
+///
+/// The decompositions of the range checks are computed by the PLONK gadgets
+/// themselves and are not part of the trace, but the values they are applied
+/// to are.
+#[cfg(feature = "debug-trace")]
+#[cfg_attr(docsrs, doc(cfg(feature = "debug-trace")))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScoreTrace {
+    /// Inputs of `y = H(secret_k, root, seed, round, step)`, in order.
+    pub hash_inputs: [BlsScalar; 5],
+    /// `y`.
+    pub y: BlsScalar,
+    /// `y'`, the lowest 128 bits of `y`.
+    pub y_prime: BlsScalar,
+    /// `r1`, the highest 128 bits of `y`.
+    pub r1: BlsScalar,
+    /// `r2`, the remainder of the division yielding the score.
+    pub r2: BlsScalar,
+    /// Value of the score.
+    pub score: BlsScalar,
+    /// Value of the Bid.
+    pub bid_value: BlsScalar,
+    /// `r1 * 2^128 + y'`, which has to be equal to `y`.
+    pub recomposed_y: BlsScalar,
+    /// `r2 - y'`, which is range-checked to prove `r2 < y'`.
+    pub r2_minus_y_prime: BlsScalar,
+    /// `score * y'`.
+    pub score_times_y_prime: BlsScalar,
+    /// `score * y' + r2`, which has to be equal to `value * 2^128`.
+    pub numerator: BlsScalar,
+    /// `value * 2^128`.
+    pub value_times_2_pow_128: BlsScalar,
+}
+
+/// The ScorePreimage holds the parts of the computation of a [`Score`] that
+/// only depend on the `Bid` and the root of the Bid tree, so they can be
+/// reused to score the same Bid for several consensus rounds and steps.
+///
+/// [`Score::prepare`] decrypts the value of the Bid once, so
+/// [`ScorePreimage::score`] only needs to compute `y` and the
+/// [`ScoreFunction`] for each consensus round and step.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Clone, Debug)]
+pub struct ScorePreimage {
+    secret_k: BlsScalar,
+    bid_tree_root: BlsScalar,
+    eligibility: u64,
+    expiration: u64,
+    value: JubJubScalar,
+}
+
+#[cfg(feature = "std")]
+impl ScorePreimage {
+    /// Computes the Score of the Bid for the provided consensus round and
+    /// step.
+    ///
+    /// The result is the same as the one of [`Score::compute`] with the
+    /// same arguments.
+    pub fn score(
+        &self,
+        consensus_round_seed: BlsScalar,
+        latest_consensus_round: u64,
+        latest_consensus_step: u64,
+    ) -> Result<Score, BlindBidError> {
+        self.score_with::<DefaultScoreFunction>(
+            consensus_round_seed,
+            latest_consensus_round,
+            latest_consensus_step,
+        )
+    }
+
+    /// Same as [`ScorePreimage::score`] but deriving the Score with the
+    /// [`ScoreFunction`] `F`.
+    pub fn score_with<F: ScoreFunction>(
+        &self,
+        consensus_round_seed: BlsScalar,
+        latest_consensus_round: u64,
+        latest_consensus_step: u64,
+    ) -> Result<Score, BlindBidError> {
+        // Same checks as `Bid::is_expired` & `Bid::is_eligible`.
+        if latest_consensus_round >= self.expiration {
+            return Err(BlindBidError::ExpiredBid);
+        }
+        if latest_consensus_round >= self.eligibility {
+            return Err(BlindBidError::NonEligibleBid);
+        }
+
+        let latest_consensus_round = BlsScalar::from(latest_consensus_round);
+        let latest_consensus_step = BlsScalar::from(latest_consensus_step);
+
+        // Compute `y` where `y = H(secret_k, Merkle_root, consensus_round_seed,
+        // latest_consensus_round, latest_consensus_step)`.
+        let y = sponge::hash(&[
+            self.secret_k,
+            self.bid_tree_root,
+            consensus_round_seed,
+            latest_consensus_round,
+            latest_consensus_step,
+        ]);
+
+        let score = F::score(&self.value, y)?;
+
+        // A zero score can never win, and it's only obtainable for a zero
+        // value with the default function.
+        if score.value == BlsScalar::zero() {
+            return Err(BlindBidError::DegenerateScore);
+        }
+        Ok(score)
+    }
+}
+
+/// A ScoreFunction derives the [`Score`] of a Bid from its value and
+/// `y = H(secret_k, root, seed, round, step)`.
+///
+/// It allows to experiment with score functions other than the
+/// [`DefaultScoreFunction`] through [`Score::compute_with`] while keeping the
+/// rest of the Bid machinery. The [BlindBidCircuit](crate::BlindBidCircuit)
+/// only proves Scores of the [`DefaultScoreFunction`].
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub trait ScoreFunction {
+    /// Computes the Score of a Bid of value `value` from `y`.
+    fn score(
+        value: &JubJubScalar,
+        y: BlsScalar,
+    ) -> Result<Score, BlindBidError>;
+}
+
+/// [ScoreFunction] of the Blind Bid protocol: `score = value * 2^128 / y'`
+/// where `y'` is the lowest 128 bits of `y`.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultScoreFunction;
+
+#[cfg(feature = "std")]
+impl ScoreFunction for DefaultScoreFunction {
+    fn score(
+        value: &JubJubScalar,
+        y: BlsScalar,
+    ) -> Result<Score, BlindBidError> {
+        // Get the bid value outside of the modular field and treat it as
+        // an integer.
+        let numerator = BigUint::from_bytes_le(&value.to_bytes()) << 128;
+
+        // Truncate Y to left 128 bits and interpret the result as 128-bit
+        // integer. Keep the right 128 bits as another integer (r1).
+        let r1 = BigUint::from_bytes_le(&y.to_bytes()[16..32]);
+        let y_prime = BigUint::from_bytes_le(&y.to_bytes()[0..16]);
+
+        // Compute the final score
+        let (f, r2) = match y_prime == BigUint::zero() {
+            // If y' != 0 -> f = (bid_value * 2^128 / y')
+            // r2 is assigned to the remainder of the division.
+            false => (&numerator / &y_prime, &numerator % &y_prime),
+            // If y' == 0 -> f = bid_value * 2^128
+            // Since there's not any division, r2 is assigned to 0 since
+            // there's not any remainder.
+            true => (numerator, BigUint::zero()),
+        };
+
+        // Get Scalars from the bigUints and return a `Score` if the conversions
+        // could be correctly done.
+        Ok(Score {
+            value: biguint_to_scalar(f)?,
+            y,
+            y_prime: biguint_to_scalar(y_prime)?,
+            r1: biguint_to_scalar(r1)?,
+            r2: biguint_to_scalar(r2)?,
+        })
+    }
+}
+
 #[cfg(feature = "std")]
 /// Given the y parameter, return the y' and it's inverse value.
 fn biguint_to_scalar(biguint: BigUint) -> Result<BlsScalar, BlindBidError> {
@@ -370,17 +925,18 @@ fn biguint_to_scalar(biguint: BigUint) -> Result<BlsScalar, BlindBidError> {
         return Err(BlindBidError::InvalidScoreFieldsLen);
     };
     bytes[..biguint_bytes.len()].copy_from_slice(&biguint_bytes);
-    // Due to the previous conditions, we can unwrap here safely.
-    Ok(BlsScalar::from_bytes(&bytes).unwrap())
+    // 32 bytes can still hold integers greater than the field modulus, which
+    // would be silently reduced if they were converted anyway.
+    BlsScalar::from_bytes(&bytes).map_err(|_| BlindBidError::ArithmeticOverflow)
 }
 
-#[cfg(feature = "std")]
+#[cfg(feature = "proof")]
 #[cfg(test)]
 mod tests {
     use super::*;
     use anyhow::Result;
     use dusk_bytes::Serializable;
-    use dusk_pki::{PublicSpendKey, SecretSpendKey};
+    use dusk_pki::{Ownable, PublicSpendKey, SecretSpendKey};
     use dusk_plonk::jubjub::GENERATOR_EXTENDED;
     use rand::Rng;
 
@@ -409,6 +965,222 @@ mod tests {
         .expect("Bid creation error")
     }
 
+    #[test]
+    fn score_is_never_zero_for_valid_bids() {
+        let mut rng = rand::thread_rng();
+        let secret = JubJubScalar::random(&mut rng);
+        let mut bid = random_bid(&secret);
+        let secret: JubJubAffine = (GENERATOR_EXTENDED * &secret).into();
+        let secret_k = BlsScalar::random(&mut rng);
+
+        // The minimum value produces the minimum scores, which are still
+        // greater or equal than the value itself.
+        bid.set_value(
+            &mut rng,
+            &JubJubScalar::from(crate::V_RAW_MIN),
+            &secret,
+            secret_k,
+        );
+        let score = Score::compute(
+            &bid,
+            &secret,
+            secret_k,
+            BlsScalar::random(&mut rng),
+            BlsScalar::random(&mut rng),
+            1u64,
+            1u64,
+        )
+        .expect("Score computation error");
+        assert!(
+            BigUint::from_bytes_le(&score.value().to_bytes())
+                >= BigUint::from(crate::V_RAW_MIN)
+        );
+
+        // A zero value is the only way to get a zero score.
+        bid.set_value(&mut rng, &JubJubScalar::zero(), &secret, secret_k);
+        assert!(matches!(
+            Score::compute(
+                &bid,
+                &secret,
+                secret_k,
+                BlsScalar::random(&mut rng),
+                BlsScalar::random(&mut rng),
+                1u64,
+                1u64,
+            ),
+            Err(BlindBidError::DegenerateScore)
+        ));
+    }
+
+    #[test]
+    fn intermediate_values_relations() {
+        let mut rng = rand::thread_rng();
+        let secret = JubJubScalar::random(&mut rng);
+        let bid = random_bid(&secret);
+        let secret: JubJubAffine = (GENERATOR_EXTENDED * &secret).into();
+        let value =
+            bid.decrypt_data(&secret).expect("Decryption error").value();
+        let secret_k = BlsScalar::random(&mut rng);
+        let root = BlsScalar::random(&mut rng);
+        let seed = BlsScalar::random(&mut rng);
+
+        let score =
+            Score::compute(&bid, &secret, secret_k, root, seed, 1u64, 1u64)
+                .expect("Score computation error");
+        let two_pow_128 = BlsScalar::from(2u64).pow(&[128, 0, 0, 0]);
+
+        assert_eq!(
+            score.y(),
+            sponge::hash(&[
+                secret_k,
+                root,
+                seed,
+                BlsScalar::from(1u64),
+                BlsScalar::from(1u64)
+            ])
+        );
+        assert_eq!(score.y(), score.r1() * two_pow_128 + score.y_prime());
+        assert_eq!(
+            score.value() * score.y_prime() + score.r2(),
+            BlsScalar::from(value) * two_pow_128
+        );
+    }
+
+    #[test]
+    fn scores_are_only_computed_for_provable_bids() {
+        let mut rng = rand::thread_rng();
+        let secret = JubJubScalar::random(&mut rng);
+        let bid = random_bid(&secret);
+        let secret: JubJubAffine = (GENERATOR_EXTENDED * &secret).into();
+        let secret_k = BlsScalar::random(&mut rng);
+        let root = BlsScalar::random(&mut rng);
+        let seed = BlsScalar::random(&mut rng);
+        let bid = Bid::from_parts(
+            bid.encrypted_data(),
+            bid.nonce(),
+            *bid.stealth_address(),
+            bid.hashed_secret(),
+            bid.commitment(),
+            10,
+            20,
+            0,
+        );
+
+        assert!(
+            Score::compute(&bid, &secret, secret_k, root, seed, 9, 1).is_ok()
+        );
+        assert!(matches!(
+            Score::compute(&bid, &secret, secret_k, root, seed, 10, 1),
+            Err(BlindBidError::NonEligibleBid)
+        ));
+        assert!(matches!(
+            Score::compute(&bid, &secret, secret_k, root, seed, 20, 1),
+            Err(BlindBidError::ExpiredBid)
+        ));
+
+        let preimage = Score::prepare(&bid, &secret, secret_k, root)
+            .expect("Score preparation error");
+        assert!(matches!(
+            preimage.score(seed, 10, 1),
+            Err(BlindBidError::NonEligibleBid)
+        ));
+        assert!(matches!(
+            preimage.score(seed, 20, 1),
+            Err(BlindBidError::ExpiredBid)
+        ));
+
+        // Decryption failures are reported as well.
+        assert!(matches!(
+            Score::compute(
+                &bid,
+                &JubJubAffine::identity(),
+                secret_k,
+                root,
+                seed,
+                9,
+                1
+            ),
+            Err(BlindBidError::DecryptionFailed)
+        ));
+    }
+
+    #[test]
+    fn scores_over_a_range_of_rounds() {
+        let mut rng = rand::thread_rng();
+        let secret = JubJubScalar::random(&mut rng);
+        let bid = random_bid(&secret);
+        let secret: JubJubAffine = (GENERATOR_EXTENDED * &secret).into();
+        let secret_k = BlsScalar::random(&mut rng);
+        let root = BlsScalar::random(&mut rng);
+        let seed = BlsScalar::random(&mut rng);
+        let bid = Bid::from_parts(
+            bid.encrypted_data(),
+            bid.nonce(),
+            *bid.stealth_address(),
+            bid.hashed_secret(),
+            bid.commitment(),
+            20,
+            15,
+            0,
+        );
+
+        let scores = Score::compute_range(
+            &bid,
+            &secret,
+            secret_k,
+            root,
+            seed,
+            10..=16,
+            1,
+        );
+        assert_eq!(scores.len(), 7);
+        scores[..5].iter().zip(10u64..).for_each(|(score, round)| {
+            assert_eq!(
+                score.as_ref().expect("Score computation error"),
+                &Score::compute(&bid, &secret, secret_k, root, seed, round, 1)
+                    .expect("Score computation error")
+            )
+        });
+        assert!(matches!(scores[5], Err(BlindBidError::ExpiredBid)));
+        assert!(matches!(scores[6], Err(BlindBidError::ExpiredBid)));
+
+        assert!(Score::compute_range(
+            &bid,
+            &JubJubAffine::identity(),
+            secret_k,
+            root,
+            seed,
+            10..=11,
+            1
+        )
+        .iter()
+        .all(|score| matches!(score, Err(BlindBidError::DecryptionFailed))));
+    }
+
+    #[test]
+    fn prepared_scores_match_computed_ones() {
+        let mut rng = rand::thread_rng();
+        let secret = JubJubScalar::random(&mut rng);
+        let bid = random_bid(&secret);
+        let secret: JubJubAffine = (GENERATOR_EXTENDED * &secret).into();
+        let secret_k = BlsScalar::random(&mut rng);
+        let root = BlsScalar::random(&mut rng);
+
+        let preimage = Score::prepare(&bid, &secret, secret_k, root)
+            .expect("Score preparation error");
+        for round in 1u64..5 {
+            let seed = BlsScalar::random(&mut rng);
+            let step = rng.gen::<u8>() as u64;
+            assert_eq!(
+                preimage.score(seed, round, step).expect("Score error"),
+                Score::compute(
+                    &bid, &secret, secret_k, root, seed, round, step
+                )
+                .expect("Score computation error")
+            );
+        }
+    }
+
     #[test]
     fn biguint_scalar_conversion() {
         let rand_scalar = BlsScalar::random(&mut rand::thread_rng());
@@ -417,6 +1189,219 @@ mod tests {
         assert_eq!(biguint_to_scalar(big_uint).unwrap(), rand_scalar)
     }
 
+    #[test]
+    fn off_circuit_score_verification() {
+        let mut rng = rand::thread_rng();
+        let secret = JubJubScalar::random(&mut rng);
+        let bid = random_bid(&secret);
+        let secret: JubJubAffine = (GENERATOR_EXTENDED * secret).into();
+        let score = Score::compute(
+            &bid,
+            &secret,
+            BlsScalar::random(&mut rng),
+            BlsScalar::random(&mut rng),
+            BlsScalar::random(&mut rng),
+            3,
+            1,
+        )
+        .expect("Score computation error");
+        assert!(score.verify(&bid, 3).is_ok());
+
+        let mut tampered = score;
+        tampered.value += BlsScalar::one();
+        assert!(matches!(
+            tampered.verify(&bid, 3),
+            Err(BlindBidError::InconsistentScore)
+        ));
+
+        let mut tampered = score;
+        tampered.r1 += BlsScalar::one();
+        assert!(matches!(
+            tampered.verify(&bid, 3),
+            Err(BlindBidError::InconsistentScore)
+        ));
+
+        assert!(matches!(
+            Score::default().verify(&bid, 3),
+            Err(BlindBidError::DegenerateScore)
+        ));
+        assert!(matches!(
+            score.verify(&bid, u64::MAX),
+            Err(BlindBidError::ExpiredBid)
+        ));
+    }
+
+    /// Trivial [ScoreFunction] scoring a Bid with its value.
+    struct ValueScore;
+
+    impl ScoreFunction for ValueScore {
+        fn score(
+            value: &JubJubScalar,
+            y: BlsScalar,
+        ) -> Result<Score, BlindBidError> {
+            Ok(Score::from_parts(
+                BlsScalar::from(*value),
+                y,
+                BlsScalar::zero(),
+                BlsScalar::zero(),
+                BlsScalar::zero(),
+            ))
+        }
+    }
+
+    #[test]
+    fn alternate_score_function() {
+        let mut rng = rand::thread_rng();
+        let secret = JubJubScalar::random(&mut rng);
+        let bid = random_bid(&secret);
+        let secret: JubJubAffine = (GENERATOR_EXTENDED * secret).into();
+        let value =
+            bid.decrypt_data(&secret).expect("Decryption error").value();
+        let (secret_k, root, seed) = (
+            BlsScalar::random(&mut rng),
+            BlsScalar::random(&mut rng),
+            BlsScalar::random(&mut rng),
+        );
+
+        let default_score =
+            Score::compute(&bid, &secret, secret_k, root, seed, 3, 1)
+                .expect("Score computation error");
+        let score = Score::compute_with::<ValueScore>(
+            &bid, &secret, secret_k, root, seed, 3, 1,
+        )
+        .expect("Score computation error");
+        assert_eq!(score.value(), BlsScalar::from(value));
+        assert_eq!(score.y(), default_score.y());
+        assert_eq!(
+            Score::compute_with::<DefaultScoreFunction>(
+                &bid, &secret, secret_k, root, seed, 3, 1,
+            )
+            .expect("Score computation error"),
+            default_score
+        );
+        assert!(matches!(
+            Score::compute_with::<ValueScore>(
+                &bid,
+                &secret,
+                secret_k,
+                root,
+                seed,
+                u64::MAX,
+                1,
+            ),
+            Err(BlindBidError::ExpiredBid)
+        ));
+    }
+
+    #[test]
+    fn scores_never_exceed_the_bound_of_their_value() {
+        let mut rng = rand::thread_rng();
+        let le = |a: BlsScalar, b: BlsScalar| {
+            a.to_bytes().iter().rev().le(b.to_bytes().iter().rev())
+        };
+
+        for _ in 0..5 {
+            let secret = JubJubScalar::random(&mut rng);
+            let bid = random_bid(&secret);
+            let secret: JubJubAffine = (GENERATOR_EXTENDED * secret).into();
+            let value =
+                bid.decrypt_data(&secret).expect("Decryption error").value();
+            let score = Score::compute(
+                &bid,
+                &secret,
+                BlsScalar::random(&mut rng),
+                BlsScalar::random(&mut rng),
+                BlsScalar::random(&mut rng),
+                3,
+                1,
+            )
+            .expect("Score computation error");
+
+            let bound = Score::max_for_value(&value);
+            assert!(le(score.value(), bound));
+            assert!(le(BlsScalar::from(value), score.value()));
+        }
+        assert_eq!(
+            Score::max_for_value(&JubJubScalar::one()),
+            BlsScalar::from_raw([0, 0, 1, 0])
+        );
+    }
+
+    #[test]
+    fn score_context_matches_the_inputs() {
+        let mut rng = rand::thread_rng();
+        let secret = JubJubScalar::random(&mut rng);
+        let bid = random_bid(&secret);
+        let secret: JubJubAffine = (GENERATOR_EXTENDED * secret).into();
+        let (secret_k, root, seed) = (
+            BlsScalar::random(&mut rng),
+            BlsScalar::random(&mut rng),
+            BlsScalar::random(&mut rng),
+        );
+
+        let (score, context) = Score::compute_with_context(
+            &bid, &secret, secret_k, root, seed, 3, 1,
+        )
+        .expect("Score computation error");
+        assert_eq!(context, ScoreContext::new(seed, 3, 1));
+        assert_eq!(context.seed(), seed);
+        assert_eq!(context.round(), 3);
+        assert_eq!(context.step(), 1);
+        assert_eq!(
+            score,
+            Score::compute(&bid, &secret, secret_k, root, seed, 3, 1)
+                .expect("Score computation error")
+        );
+    }
+
+    #[test]
+    fn overflowing_score_fields() {
+        // The field modulus fits in 32 bytes but not in a `BlsScalar`.
+        let modulus =
+            BigUint::from_bytes_le(&(-BlsScalar::one()).to_bytes()) + 1u32;
+        assert!(matches!(
+            biguint_to_scalar(modulus),
+            Err(BlindBidError::ArithmeticOverflow)
+        ));
+        assert!(matches!(
+            biguint_to_scalar(BigUint::from(1u32) << 256),
+            Err(BlindBidError::InvalidScoreFieldsLen)
+        ));
+    }
+
+    #[test]
+    fn scores_for_the_greatest_rounds_and_steps() {
+        let mut rng = rand::thread_rng();
+        let secret = JubJubScalar::random(&mut rng);
+        let bid = random_bid(&secret);
+        let secret: JubJubAffine = (GENERATOR_EXTENDED * secret).into();
+
+        // Rounds and steps are converted into field elements without any
+        // host-side arithmetic, so the greatest ones are scored as any other.
+        let score = Score::compute(
+            &bid,
+            &secret,
+            BlsScalar::random(&mut rng),
+            BlsScalar::random(&mut rng),
+            BlsScalar::random(&mut rng),
+            u64::MAX - 1,
+            u64::MAX,
+        );
+        assert!(score.is_ok());
+        assert!(matches!(
+            Score::compute(
+                &bid,
+                &secret,
+                BlsScalar::one(),
+                BlsScalar::one(),
+                BlsScalar::one(),
+                u64::MAX,
+                u64::MAX,
+            ),
+            Err(BlindBidError::ExpiredBid)
+        ));
+    }
+
     fn allocate_fields(
         composer: &mut StandardComposer,
         value: JubJubScalar,
@@ -464,8 +1449,10 @@ mod tests {
         let secret = JubJubScalar::random(&mut rand::thread_rng());
         let bid = random_bid(&secret);
         let secret = GENERATOR_EXTENDED * &secret;
-        let (value, _) =
-            bid.decrypt_data(&secret.into()).expect("Decryption error");
+        let value = bid
+            .decrypt_data(&secret.into())
+            .expect("Decryption error")
+            .value();
 
         // Generate fields for the Bid & required by the compute_score
         let secret_k = BlsScalar::random(&mut rand::thread_rng());
@@ -563,8 +1550,10 @@ mod tests {
         let secret = JubJubScalar::random(&mut rand::thread_rng());
         let bid = random_bid(&secret);
         let secret = GENERATOR_EXTENDED * &secret;
-        let (value, _) =
-            bid.decrypt_data(&secret.into()).expect("Decryption Error");
+        let value = bid
+            .decrypt_data(&secret.into())
+            .expect("Decryption Error")
+            .value();
 
         // Generate fields for the Bid & required by the compute_score
         let secret_k = BlsScalar::random(&mut rand::thread_rng());
@@ -662,19 +1651,132 @@ mod tests {
 mod score_serialization {
     use super::*;
 
+    #[test]
+    fn default_score_is_a_placeholder() {
+        let score = Score::default();
+        assert_eq!(score.value(), BlsScalar::zero());
+        assert!(score.is_placeholder());
+
+        let mut rng = rand::thread_rng();
+        let secret = JubJubScalar::random(&mut rng);
+        let bid = random_bid(&secret);
+        let secret: JubJubAffine = (GENERATOR_EXTENDED * &secret).into();
+        let score = Score::compute(
+            &bid,
+            &secret,
+            BlsScalar::random(&mut rng),
+            BlsScalar::random(&mut rng),
+            BlsScalar::random(&mut rng),
+            1u64,
+            1u64,
+        )
+        .expect("Score computation error");
+        assert!(!score.is_placeholder());
+    }
+
     #[test]
     fn score_serialization_roundtrip() {
         let score = Score {
             value: BlsScalar::one(),
-            y: BlsScalar::one(),
-            y_prime: BlsScalar::one(),
-            r1: BlsScalar::one(),
-            r2: BlsScalar::one(),
+            y: BlsScalar::from(2u64),
+            y_prime: BlsScalar::from(3u64),
+            r1: BlsScalar::from(4u64),
+            r2: BlsScalar::from(5u64),
         };
 
         let score_bytes = score.to_bytes();
+        assert_eq!(score_bytes.len(), SCORE_SERIALIZED_SIZE);
+        assert_eq!(score_bytes[..32], BlsScalar::one().to_bytes());
         let score_from_bytes =
             Score::from_bytes(&score_bytes).expect("Invalid roundtrip");
-        assert_eq!(score, score_from_bytes)
+        assert_eq!(score, score_from_bytes);
+
+        // Non-canonical field elements are rejected.
+        let mut invalid = score_bytes;
+        invalid[32..64].copy_from_slice(&[0xff; 32]);
+        assert!(Score::from_bytes(&invalid).is_err());
+    }
+}
+
+#[cfg(test)]
+mod ordering {
+    use super::*;
+
+    fn score(value: BlsScalar) -> Score {
+        Score {
+            value,
+            ..Score::default()
+        }
+    }
+
+    #[test]
+    fn scores_are_compared_as_integers() {
+        let small = score(BlsScalar::from(50_000u64));
+        let big = score(BlsScalar::from_raw([0, 1, 0, 0]));
+        let greatest = score(-BlsScalar::one());
+
+        assert_eq!(small.cmp_consensus(&big), Ordering::Less);
+        assert_eq!(big.cmp_consensus(&small), Ordering::Greater);
+        assert_eq!(big.cmp_consensus(&greatest), Ordering::Less);
+        assert_eq!(greatest.cmp_consensus(&small), Ordering::Greater);
+    }
+
+    #[test]
+    fn equal_scores() {
+        let a = score(BlsScalar::from(50_000u64));
+        let mut b = a;
+        b.y = BlsScalar::one();
+        b.r2 = BlsScalar::one();
+
+        assert_eq!(a.cmp_consensus(&a), Ordering::Equal);
+        assert_eq!(a.cmp_consensus(&b), Ordering::Equal);
+        assert_eq!(b.cmp_consensus(&a), Ordering::Equal);
+        assert_eq!(
+            Score::default().cmp_consensus(&Score::default()),
+            Ordering::Equal
+        );
+    }
+}
+
+#[cfg(all(feature = "debug-trace", feature = "proof"))]
+#[cfg(test)]
+mod trace {
+    use super::*;
+    use dusk_jubjub::GENERATOR_EXTENDED;
+    use dusk_pki::{PublicSpendKey, SecretSpendKey};
+
+    #[test]
+    fn trace_relationships() {
+        let mut rng = rand::thread_rng();
+        let pk_r = PublicSpendKey::from(SecretSpendKey::random(&mut rng));
+        let stealth_addr = pk_r.gen_stealth_address(&JubJubScalar::one());
+        let secret: JubJubAffine =
+            (GENERATOR_EXTENDED * JubJubScalar::from(7u64)).into();
+        let bid = Bid::new(
+            &mut rng,
+            &stealth_addr,
+            &JubJubScalar::from(crate::V_RAW_MIN),
+            &secret,
+            BlsScalar::one(),
+            u64::MAX,
+            u64::MAX,
+        )
+        .expect("Bid creation error");
+
+        let (score, trace) = Score::compute_with_trace(
+            &bid,
+            &secret,
+            BlsScalar::one(),
+            BlsScalar::from(2u64),
+            BlsScalar::from(3u64),
+            4,
+            5,
+        )
+        .expect("Score computation error");
+        assert_eq!(trace.score, score.value());
+        assert_eq!(trace.y, sponge::hash(&trace.hash_inputs));
+        assert_eq!(trace.recomposed_y, trace.y);
+        assert_eq!(trace.numerator, trace.value_times_2_pow_128);
+        assert_eq!(trace.bid_value, BlsScalar::from(crate::V_RAW_MIN));
     }
 }
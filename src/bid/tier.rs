@@ -0,0 +1,89 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Value ranges that a [Bid](super::Bid) can be created for.
+
+use crate::{V_RAW_MAX, V_RAW_MIN};
+use dusk_jubjub::JubJubScalar;
+
+/// The BidTier is the class of a [Bid](super::Bid), which determines the
+/// `[min, max]` range its value has to lie in.
+///
+/// Deployments with several classes of Bids are meant to define each one of
+/// them as a constant [`BidTier::Range`]:
+///
+/// ```
+/// use dusk_blindbid::BidTier;
+///
+/// const LOW: BidTier = BidTier::Range { min: 1_000, max: 49_999 };
+/// const HIGH: BidTier = BidTier::Range { min: 250_001, max: 1_000_000 };
+///
+/// assert_eq!(LOW.bounds(), (1_000, 49_999));
+/// assert!(HIGH.contains(1_000_000));
+/// ```
+///
+/// The tier of a Bid is checked when it's created with
+/// [`Bid::new_tiered`](super::Bid::new_tiered) and proven in-circuit with the
+/// [ValueBoundsCircuit](crate::ValueBoundsCircuit).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BidTier {
+    /// The `[V_RAW_MIN, V_RAW_MAX]` range, proven by the
+    /// [BlindBidCircuit](crate::BlindBidCircuit) itself.
+    Standard,
+    /// A custom `[min, max]` range.
+    Range {
+        /// Minimum value of the Bids of the tier.
+        min: u64,
+        /// Maximum value of the Bids of the tier.
+        max: u64,
+    },
+}
+
+impl Default for BidTier {
+    fn default() -> Self {
+        BidTier::Standard
+    }
+}
+
+impl BidTier {
+    /// Returns the `(min, max)` bounds of the tier.
+    pub const fn bounds(&self) -> (u64, u64) {
+        match self {
+            BidTier::Standard => (V_RAW_MIN, V_RAW_MAX),
+            BidTier::Range { min, max } => (*min, *max),
+        }
+    }
+
+    /// Returns `true` if `value` lies inside of the bounds of the tier.
+    pub fn contains(&self, value: u64) -> bool {
+        let (min, max) = self.bounds();
+        min <= value && value <= max
+    }
+
+    /// Returns the bounds of the tier in the representation used to check
+    /// the value of a Bid.
+    pub(crate) fn scalar_bounds(&self) -> (JubJubScalar, JubJubScalar) {
+        let (min, max) = self.bounds();
+        (JubJubScalar::from(min), JubJubScalar::from(max))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tier_bounds() {
+        assert_eq!(BidTier::default().bounds(), (V_RAW_MIN, V_RAW_MAX));
+        let low = BidTier::Range { min: 10, max: 20 };
+        assert!(low.contains(10));
+        assert!(low.contains(20));
+        assert!(!low.contains(9));
+        assert!(!low.contains(21));
+        assert!(BidTier::Standard.contains(V_RAW_MAX));
+        assert!(!BidTier::Standard.contains(V_RAW_MIN - 1));
+    }
+}
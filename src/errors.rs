@@ -6,20 +6,73 @@
 
 //! Errors related to the BlindBid module
 
+use core::fmt;
 use dusk_bytes::Error as DuskBytesError;
 use dusk_jubjub::JubJubScalar;
-#[cfg(feature = "std")]
-use std::fmt;
 
 #[derive(Debug)]
 /// Compilation of the erros that blindbid procedures might end up producing.
+///
+/// The error and its [`Display`](core::fmt::Display) implementation are
+/// available without the `std` feature, so `no_std` consumers can propagate
+/// and report it without depending on `anyhow` nor `std`.
+/// [`std::error::Error`] is only implemented with the `std` feature.
+///
+/// ```
+/// use core::fmt::Write;
+/// use dusk_blindbid::{BlindBidError, Bid};
+/// use dusk_bytes::Serializable;
+///
+/// fn decode(bytes: &[u8; Bid::SIZE]) -> Result<Bid, BlindBidError> {
+///     Ok(Bid::from_bytes(bytes)?)
+/// }
+///
+/// let err = decode(&[0xff; Bid::SIZE]).unwrap_err();
+/// let mut msg = heapless_msg::Msg::new();
+/// write!(msg, "{}", err).unwrap();
+/// assert!(msg.as_str().starts_with("serialization error"));
+/// # mod heapless_msg {
+/// #     pub struct Msg { buf: [u8; 128], len: usize }
+/// #     impl Msg {
+/// #         pub fn new() -> Self {
+/// #             Msg { buf: [0u8; 128], len: 0 }
+/// #         }
+/// #         pub fn as_str(&self) -> &str {
+/// #             core::str::from_utf8(&self.buf[..self.len]).unwrap()
+/// #         }
+/// #     }
+/// #     impl core::fmt::Write for Msg {
+/// #         fn write_str(&mut self, s: &str) -> core::fmt::Result {
+/// #             let end = self.len + s.len();
+/// #             if end > self.buf.len() {
+/// #                 return Err(core::fmt::Error);
+/// #             }
+/// #             self.buf[self.len..end].copy_from_slice(s.as_bytes());
+/// #             self.len = end;
+/// #             Ok(())
+/// #         }
+/// #     }
+/// # }
+/// ```
 pub enum BlindBidError {
     /// Error for the cases when we the score results are too large to
     /// fit inside a `Scalar`.
     InvalidScoreFieldsLen,
+    /// Error for the cases when an integer computed during the score
+    /// generation is greater than the modulus of the scalar field.
+    ArithmeticOverflow,
     /// Error that happens when you try to generate a `Score` for a `Bid`
     /// has already expired.
     ExpiredBid,
+    /// Error that happens when you try to generate a `Score` for a `Bid`
+    /// that is no longer eligible.
+    NonEligibleBid,
+    /// Error for the cases when the computed `Score` is zero, which is only
+    /// possible for a `Bid` with a zero value.
+    DegenerateScore,
+    /// Error for the cases when the fields of a `Score` are not consistent
+    /// with each other.
+    InconsistentScore,
     /// Error for the cases when we the provided Bid value is bigger
     /// than the maximum allowed by the specs..
     MaximumBidValueExceeded {
@@ -36,21 +89,286 @@ pub enum BlindBidError {
         /// The expected length
         found: JubJubScalar,
     },
-    /// Error when there is a decrypt attempt with the wrong secret
+    /// Error for the cases when a Bid would expire before becoming eligible.
+    InvalidTimestampOrder {
+        /// Eligibility of the Bid.
+        eligibility: u64,
+        /// Expiration of the Bid.
+        expiration: u64,
+    },
+    /// Error for the cases when a required field of a
+    /// [BidBuilder](crate::BidBuilder) wasn't set.
+    MissingField(&'static str),
+    /// Error when there is a decrypt attempt with the wrong secret.
+    ///
+    /// Not returned by [`Bid::decrypt_data`](crate::Bid::decrypt_data),
+    /// which can't tell a wrong secret from a wrong nonce and reports
+    /// [`BlindBidError::DecryptionFailed`] instead.
     WrongSecretProvided,
+    /// Error when the encrypted data of a Bid can't be decrypted, either
+    /// because of a wrong secret or a wrong nonce.
+    DecryptionFailed,
     /// Invalid encoding/decoding
     IOError,
-    /// Dusk-bytes serialization error
+    /// Error for the cases when a serialized structure uses a version tag
+    /// that is not supported.
+    UnsupportedVersion(u8),
+    /// Dusk-bytes serialization error, wrapping the underlying cause so
+    /// decoding failures can be propagated with `?`.
     SerializationError(DuskBytesError),
+    /// Error for the cases when the prover fails to generate a proof.
+    ProofGenerationFailed,
+    /// Error for the cases when a proof does not verify against the provided
+    /// Public Inputs.
+    ProofVerificationFailed,
+    /// Error for the cases when a proof of a batch does not verify against
+    /// its Public Inputs.
+    BatchVerificationFailed {
+        /// Index of the first proof of the batch that does not verify.
+        index: usize,
+    },
+    /// Error for the cases when the transcript label used to verify a proof
+    /// is not the one it was generated with.
+    TranscriptLabelMismatch,
+    /// Error for the cases when the keys of a circuit were compiled for a
+    /// different trim size than the expected one.
+    TrimSizeMismatch {
+        /// Trim size the keys were expected to be compiled for.
+        expected: usize,
+        /// Trim size the keys were compiled for.
+        found: usize,
+    },
+    /// Error for the cases when a circuit is compiled with a trim size
+    /// greater than the one the Public Parameters were set up for.
+    TrimSizeTooLarge {
+        /// Trim size the circuit was compiled with.
+        requested: usize,
+        /// Maximum trim size supported by the Public Parameters.
+        available: usize,
+    },
+    /// Error for the cases when a circuit is compiled with a trim size lower
+    /// than the number of constraints of the circuit.
+    TrimSizeTooSmall {
+        /// Trim size the circuit was compiled with.
+        requested: usize,
+        /// Minimum trim size the circuit fits in.
+        minimum: usize,
+    },
+    /// Error for the cases when serialized keys use a different version of
+    /// the format than the supported one.
+    KeyVersionMismatch {
+        /// Version of the format supported.
+        expected: u8,
+        /// Version of the format the keys were serialized with.
+        found: u8,
+    },
+    /// Error for the cases when serialized
+    /// [BlindBidKeys](crate::BlindBidKeys) are truncated or corrupt.
+    MalformedKeys,
+    /// Error for the cases when the Bids being inserted don't fit in the
+    /// tree.
+    TreeFull {
+        /// Maximum number of Bids the tree can hold.
+        capacity: u64,
+        /// Number of Bids the tree would hold after the insertion.
+        required: u64,
+    },
+    /// Error for the cases when a Bid is inserted in a
+    /// [SparseBidTree](crate::SparseBidTree) at a key already used by
+    /// another Bid.
+    SparseKeyTaken {
+        /// Key shared by both Bids.
+        key: u64,
+    },
+    /// Error for the cases when a serialized
+    /// [BlindBidVerifierBundle](crate::BlindBidVerifierBundle) is truncated
+    /// or corrupt.
+    MalformedBundle,
+    /// Error for the cases when an operation that only supports circuits
+    /// with a public commitment is used with one that keeps it private.
+    PrivateCommitmentUnsupported,
+    /// Error for the cases when the gadget of a circuit fails while it is
+    /// compiled or its Public Input positions are collected.
+    CircuitSynthesisFailed,
+    /// Error for the cases when an entry of a round candidate set is
+    /// rejected.
+    InvalidCandidate {
+        /// Index of the offending entry within the set.
+        index: usize,
+        /// Reason why the entry was rejected.
+        reason: CandidateRejection,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Reasons why an entry of a round candidate set can be rejected.
+pub enum CandidateRejection {
+    /// The Bid is already expired at the round being validated.
+    ExpiredBid,
+    /// The Bid is not eligible at the round being validated.
+    NonEligibleBid,
+    /// The Public Inputs do not correspond to the Bid they're paired with.
+    PublicInputsMismatch,
+    /// The same Bid was already used by a previous entry of the set.
+    DuplicateBid,
+    /// The same prover ID was already used by a previous entry of the set.
+    DuplicateProverId,
+    /// The proof does not verify against the provided Public Inputs.
+    InvalidProof,
+}
+
+/// Displays a Bid value as an integer if it fits in a `u64`, or as its
+/// little-endian hex representation otherwise.
+struct DisplayValue<'a>(&'a JubJubScalar);
+
+impl fmt::Display for DisplayValue<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bytes = self.0.to_bytes();
+        if bytes[8..].iter().all(|b| *b == 0) {
+            let mut value = [0u8; 8];
+            value.copy_from_slice(&bytes[..8]);
+            write!(f, "{}", u64::from_le_bytes(value))
+        } else {
+            write!(f, "0x")?;
+            bytes.iter().try_for_each(|b| write!(f, "{:02x}", b))
+        }
+    }
 }
 
-#[cfg(feature = "std")]
 impl fmt::Display for BlindBidError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Bid Generation Error: {:?}", &self)
+        match self {
+            Self::InvalidScoreFieldsLen => {
+                write!(f, "score fields do not fit inside of a scalar")
+            }
+            Self::ArithmeticOverflow => {
+                write!(f, "score fields overflow the scalar field")
+            }
+            Self::ExpiredBid => write!(f, "bid is already expired"),
+            Self::NonEligibleBid => write!(f, "bid is no longer eligible"),
+            Self::DegenerateScore => write!(f, "bid produced a zero score"),
+            Self::InconsistentScore => {
+                write!(f, "score fields are not consistent")
+            }
+            Self::MaximumBidValueExceeded { max_val, found } => write!(
+                f,
+                "bid value {} is above the maximum of {}",
+                DisplayValue(found),
+                DisplayValue(max_val)
+            ),
+            Self::MinimumBidValueUnreached { min_val, found } => write!(
+                f,
+                "bid value {} is below the minimum of {}",
+                DisplayValue(found),
+                DisplayValue(min_val)
+            ),
+            Self::InvalidTimestampOrder {
+                eligibility,
+                expiration,
+            } => write!(
+                f,
+                "bid expires at {} before being eligible at {}",
+                expiration, eligibility
+            ),
+            Self::MissingField(field) => {
+                write!(f, "bid field `{}` was not set", field)
+            }
+            Self::WrongSecretProvided => {
+                write!(f, "the secret provided can't decrypt the bid")
+            }
+            Self::DecryptionFailed => write!(
+                f,
+                "bid can't be decrypted with the provided secret and nonce"
+            ),
+            Self::IOError => write!(f, "invalid encoding or decoding"),
+            Self::UnsupportedVersion(version) => {
+                write!(f, "unsupported serialization version {}", version)
+            }
+            Self::SerializationError(err) => {
+                write!(f, "serialization error: {:?}", err)
+            }
+            Self::ProofGenerationFailed => {
+                write!(f, "proof generation failed")
+            }
+            Self::ProofVerificationFailed => {
+                write!(f, "proof verification failed")
+            }
+            Self::BatchVerificationFailed { index } => {
+                write!(f, "proof {} of the batch failed verification", index)
+            }
+            Self::TranscriptLabelMismatch => {
+                write!(f, "transcript labels of prover and verifier differ")
+            }
+            Self::TrimSizeMismatch { expected, found } => write!(
+                f,
+                "keys compiled for trim size {} but {} was expected",
+                found, expected
+            ),
+            Self::TrimSizeTooLarge {
+                requested,
+                available,
+            } => write!(
+                f,
+                "trim size {} exceeds the maximum of {}",
+                requested, available
+            ),
+            Self::TrimSizeTooSmall { requested, minimum } => write!(
+                f,
+                "trim size {} is below the minimum of {}",
+                requested, minimum
+            ),
+            Self::KeyVersionMismatch { expected, found } => write!(
+                f,
+                "keys serialized with version {} but {} is supported",
+                found, expected
+            ),
+            Self::MalformedKeys => {
+                write!(f, "circuit keys are truncated or corrupt")
+            }
+            Self::TreeFull { capacity, required } => write!(
+                f,
+                "bid tree can hold {} bids but {} are required",
+                capacity, required
+            ),
+            Self::SparseKeyTaken { key } => {
+                write!(f, "sparse tree key {} is already taken", key)
+            }
+            Self::MalformedBundle => {
+                write!(f, "verifier bundle is truncated or corrupt")
+            }
+            Self::PrivateCommitmentUnsupported => write!(
+                f,
+                "operation not supported for circuits with a private \
+                 commitment"
+            ),
+            Self::CircuitSynthesisFailed => {
+                write!(f, "circuit synthesis failed")
+            }
+            Self::InvalidCandidate { index, reason } => {
+                write!(f, "candidate {} rejected: {}", index, reason)
+            }
+        }
+    }
+}
+
+impl fmt::Display for CandidateRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ExpiredBid => write!(f, "bid is expired"),
+            Self::NonEligibleBid => write!(f, "bid is not eligible"),
+            Self::PublicInputsMismatch => {
+                write!(f, "public inputs do not match the bid")
+            }
+            Self::DuplicateBid => write!(f, "bid already used"),
+            Self::DuplicateProverId => write!(f, "prover ID already used"),
+            Self::InvalidProof => write!(f, "invalid proof"),
+        }
     }
 }
 
+#[cfg(feature = "std")]
+impl std::error::Error for BlindBidError {}
+
 #[cfg(feature = "std")]
 impl From<BlindBidError> for std::io::Error {
     fn from(err: BlindBidError) -> std::io::Error {
@@ -66,3 +384,34 @@ impl From<DuskBytesError> for BlindBidError {
         Self::SerializationError(bytes_err)
     }
 }
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{V_MIN, V_RAW_MIN};
+
+    #[test]
+    fn human_readable_messages() {
+        let err = BlindBidError::MinimumBidValueUnreached {
+            min_val: V_MIN,
+            found: JubJubScalar::from(V_RAW_MIN - 10_000),
+        };
+        assert_eq!(
+            err.to_string(),
+            "bid value 40000 is below the minimum of 50000"
+        );
+
+        let err = BlindBidError::MaximumBidValueExceeded {
+            max_val: JubJubScalar::from(1u64),
+            found: -JubJubScalar::one(),
+        };
+        assert!(err.to_string().starts_with("bid value 0x"));
+
+        let err = BlindBidError::InvalidCandidate {
+            index: 2,
+            reason: CandidateRejection::DuplicateBid,
+        };
+        assert_eq!(err.to_string(), "candidate 2 rejected: bid already used");
+    }
+}
@@ -0,0 +1,206 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Hash functions used to compute the Bid hash, the prover ID and the
+//! hashed secret, both natively and inside of the circuit.
+
+use dusk_bls12_381::BlsScalar;
+#[cfg(feature = "proof")]
+use dusk_hades::GadgetStrategy;
+use dusk_hades::{ScalarStrategy, Strategy, WIDTH};
+#[cfg(feature = "proof")]
+use dusk_plonk::prelude::*;
+use dusk_poseidon::sponge;
+
+/// A hash function with a native implementation and a matching in-circuit
+/// gadget.
+///
+/// Both implementations **must** produce the same output for the same inputs,
+/// since the proofs generated with the
+/// [BlindBidCircuit](crate::BlindBidCircuit) bind the values computed
+/// natively to the ones computed by the gadget.
+pub trait BidHasher {
+    /// Hashes the provided inputs natively.
+    fn hash(inputs: &[BlsScalar]) -> BlsScalar;
+
+    /// Hashes the provided inputs inside of the Constraint System returning
+    /// the Variable that holds the result.
+    #[cfg(feature = "proof")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "proof")))]
+    fn gadget(composer: &mut StandardComposer, inputs: &[Variable])
+        -> Variable;
+}
+
+/// [BidHasher] implementation that uses the Poseidon sponge hash with the
+/// `poseidon252` parameter set.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PoseidonSponge;
+
+impl BidHasher for PoseidonSponge {
+    fn hash(inputs: &[BlsScalar]) -> BlsScalar {
+        sponge::hash(inputs)
+    }
+
+    #[cfg(feature = "proof")]
+    fn gadget(
+        composer: &mut StandardComposer,
+        inputs: &[Variable],
+    ) -> Variable {
+        sponge::gadget(composer, inputs)
+    }
+}
+
+/// [BidHasher] implementation that uses the `poseidon252` permutation in
+/// constant-length mode.
+///
+/// Instead of appending a padding `1` to the inputs like [PoseidonSponge],
+/// the capacity element is initialized to `len · 2^64`, where `len` is the
+/// number of inputs, and the last chunk is padded with zeroes. This saves the
+/// extra permutation that the sponge needs when the number of inputs is a
+/// multiple of the rate, and follows the domain separation of the Poseidon
+/// paper for fixed-length inputs.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PoseidonConstantLength;
+
+impl PoseidonConstantLength {
+    /// Capacity element for a preimage of `len` scalars.
+    fn capacity(len: usize) -> BlsScalar {
+        BlsScalar::from_raw([0, len as u64, 0, 0])
+    }
+}
+
+impl BidHasher for PoseidonConstantLength {
+    fn hash(inputs: &[BlsScalar]) -> BlsScalar {
+        let mut h = ScalarStrategy::new();
+        let mut state = [BlsScalar::zero(); WIDTH];
+        state[0] = Self::capacity(inputs.len());
+
+        if inputs.is_empty() {
+            h.perm(&mut state);
+        }
+        inputs.chunks(WIDTH - 1).for_each(|chunk| {
+            state[1..]
+                .iter_mut()
+                .zip(chunk.iter())
+                .for_each(|(s, c)| *s += c);
+            h.perm(&mut state);
+        });
+
+        state[1]
+    }
+
+    #[cfg(feature = "proof")]
+    fn gadget(
+        composer: &mut StandardComposer,
+        inputs: &[Variable],
+    ) -> Variable {
+        let zero =
+            composer.add_witness_to_circuit_description(BlsScalar::zero());
+        let mut state = [zero; WIDTH];
+        state[0] = composer
+            .add_witness_to_circuit_description(Self::capacity(inputs.len()));
+
+        if inputs.is_empty() {
+            GadgetStrategy::new(composer).perm(&mut state);
+        }
+        inputs.chunks(WIDTH - 1).for_each(|chunk| {
+            state[1..].iter_mut().zip(chunk.iter()).for_each(|(s, c)| {
+                *s = composer.add(
+                    (BlsScalar::one(), *s),
+                    (BlsScalar::one(), *c),
+                    BlsScalar::zero(),
+                    BlsScalar::zero(),
+                );
+            });
+            GadgetStrategy::new(composer).perm(&mut state);
+        });
+
+        state[1]
+    }
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "poseidon-constant-length")] {
+        /// [BidHasher] used by the crate to compute the Bid hash, the prover
+        /// ID and the hashed secret.
+        ///
+        /// It is [PoseidonConstantLength] since the
+        /// `poseidon-constant-length` feature is enabled. Every hash changes
+        /// with it, so all of the parties of a network must agree on it.
+        pub type DefaultHasher = PoseidonConstantLength;
+    } else {
+        /// [BidHasher] used by the crate to compute the Bid hash, the prover
+        /// ID and the hashed secret.
+        ///
+        /// It is [PoseidonSponge] unless the `poseidon-constant-length`
+        /// feature selects [PoseidonConstantLength]. Every hash changes with
+        /// it, so all of the parties of a network must agree on it.
+        pub type DefaultHasher = PoseidonSponge;
+    }
+}
+
+#[cfg(feature = "proof")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    fn native_and_gadget_agree<H: BidHasher>() -> Result<()> {
+        let pub_params =
+            PublicParameters::setup(1 << 12, &mut rand::thread_rng())?;
+        let (ck, vk) = pub_params.trim(1 << 11)?;
+
+        let inputs = [
+            BlsScalar::random(&mut rand::thread_rng()),
+            BlsScalar::random(&mut rand::thread_rng()),
+            BlsScalar::random(&mut rand::thread_rng()),
+        ];
+        let expected = H::hash(&inputs);
+
+        let circuit = |composer: &mut StandardComposer| {
+            let inputs: Vec<Variable> =
+                inputs.iter().map(|i| composer.add_input(*i)).collect();
+            let hash = H::gadget(composer, &inputs);
+            composer.constrain_to_constant(hash, BlsScalar::zero(), -expected);
+        };
+
+        // Proving
+        let mut prover = Prover::new(b"testing");
+        circuit(prover.mut_cs());
+        prover.preprocess(&ck)?;
+        let proof = prover.prove(&ck)?;
+
+        // Verification
+        let mut verifier = Verifier::new(b"testing");
+        circuit(verifier.mut_cs());
+        verifier.preprocess(&ck)?;
+        let pi = verifier.mut_cs().public_inputs.clone();
+        verifier.verify(&proof, &vk, &pi)
+    }
+
+    #[test]
+    fn poseidon_sponge_native_and_gadget_agree() -> Result<()> {
+        native_and_gadget_agree::<PoseidonSponge>()
+    }
+
+    #[test]
+    fn poseidon_constant_length_native_and_gadget_agree() -> Result<()> {
+        native_and_gadget_agree::<PoseidonConstantLength>()
+    }
+
+    #[test]
+    fn poseidon_constant_length_is_length_separated() {
+        let one = BlsScalar::one();
+        assert_ne!(
+            PoseidonConstantLength::hash(&[one]),
+            PoseidonConstantLength::hash(&[one, BlsScalar::zero()])
+        );
+        assert_ne!(
+            PoseidonConstantLength::hash(&[one]),
+            PoseidonSponge::hash(&[one])
+        );
+    }
+}
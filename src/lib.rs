@@ -135,13 +135,45 @@
 
 pub(crate) mod bid;
 pub(crate) mod errors;
-#[cfg(feature = "std")]
+pub(crate) mod hasher;
+pub mod prelude;
+#[cfg(feature = "proof")]
 pub(crate) mod proof;
-pub use bid::{Bid, Score};
-pub use errors::BlindBidError;
-#[cfg(all(feature = "std", feature = "canon"))]
-#[cfg_attr(docsrs, doc(cfg(all(feature = "canon", feature = "std"))))]
-pub use proof::BlindBidCircuit;
+#[cfg(feature = "serde")]
+mod serde_support;
+#[cfg(feature = "debug-trace")]
+pub use bid::ScoreTrace;
+pub use bid::{
+    blinder_generator, compute_prover_id, hash_secret_k, timestamp_to_scalar,
+    value_generator, value_in_range, Bid, BidBuilder, BidBytes, BidTier,
+    DecryptedBid, Score, ScoreContext, BID_FORMAT_VERSION,
+    BID_LEGACY_SERIALIZED_SIZE, BID_SERIALIZED_SIZE, DOMAIN_HASHED_SECRET,
+    DOMAIN_PROVER_ID, SCORE_SERIALIZED_SIZE,
+};
+#[cfg(feature = "std")]
+pub use bid::{
+    validate_bid_values, DefaultScoreFunction, ScoreFunction, ScorePreimage,
+};
+pub use errors::{BlindBidError, CandidateRejection};
+pub use hasher::{
+    BidHasher, DefaultHasher, PoseidonConstantLength, PoseidonSponge,
+};
+#[cfg(all(feature = "proof", feature = "canon"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "canon", feature = "proof"))))]
+pub use proof::{
+    prove_blind_bid, reconstruct_public_inputs, validate_candidate_set,
+    verify_blind_bid, verify_blind_bid_bytes, verify_blind_bids_batch, BidLeaf,
+    BidOpening, BidTree, BidWitness, BlindBidCircuit, BlindBidKeys,
+    BlindBidProver, BlindBidPublicInputs, BlindBidVerifier,
+    BlindBidVerifierBundle, ProofContext, SparseBidTree, SparseBranch,
+    ThresholdCircuit, ValueBoundsCircuit, BID_TREE_CAPACITY, BID_TREE_DEPTH,
+    BLINDBID_TRANSCRIPT_LABEL, VERIFY_ERR_INVALID_PROOF, VERIFY_ERR_PROOF,
+    VERIFY_ERR_PUBLIC_INPUTS, VERIFY_ERR_PUB_PARAMS, VERIFY_ERR_VERIFIER_KEY,
+    VERIFY_OK,
+};
+#[cfg(all(feature = "mock", feature = "canon"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "mock")))]
+pub use proof::{verify_mock_proof, MockProof};
 /// The minimum amount of Dusk an user is permitted to bid.
 pub const V_RAW_MIN: u64 = 50_000u64;
 /// The maximum amount of Dusk an user is permitted to bid.
@@ -152,3 +184,15 @@ pub(crate) const V_MIN: JubJubScalar =
     JubJubScalar::from_raw([V_RAW_MIN, 0, 0, 0]);
 pub(crate) const V_MAX: JubJubScalar =
     JubJubScalar::from_raw([V_RAW_MAX, 0, 0, 0]);
+
+/// Returns [`V_RAW_MIN`] as a `JubJubScalar`, the representation used to
+/// check the value of a [`Bid`].
+pub fn v_min() -> JubJubScalar {
+    V_MIN
+}
+
+/// Returns [`V_RAW_MAX`] as a `JubJubScalar`, the representation used to
+/// check the value of a [`Bid`].
+pub fn v_max() -> JubJubScalar {
+    V_MAX
+}
@@ -0,0 +1,51 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Collection of the types and functions needed for a typical Bid creation,
+//! proving and verification flow, so they can be imported at once.
+//!
+//! ```ignore
+//! use dusk_blindbid::prelude::*;
+//!
+//! let mut rng = rand::thread_rng();
+//! let psk = PublicSpendKey::from(SecretSpendKey::random(&mut rng));
+//! let secret: JubJubAffine =
+//!     (GENERATOR_EXTENDED * JubJubScalar::random(&mut rng)).into();
+//!
+//! let bid = Bid::new(
+//!     &mut rng,
+//!     &psk.gen_stealth_address(&JubJubScalar::random(&mut rng)),
+//!     &JubJubScalar::from(V_RAW_MIN),
+//!     &secret,
+//!     BlsScalar::random(&mut rng),
+//!     1000,
+//!     1000,
+//! )?;
+//! assert_eq!(bid.decrypt_data(&secret)?.value(), V_RAW_MIN.into());
+//! # Ok::<(), BlindBidError>(())
+//! ```
+
+pub use crate::{
+    blinder_generator, value_generator, Bid, BidBuilder, BlindBidError, Score,
+    V_RAW_MAX, V_RAW_MIN,
+};
+pub use dusk_bls12_381::BlsScalar;
+pub use dusk_jubjub::{
+    JubJubAffine, JubJubExtended, JubJubScalar, GENERATOR_EXTENDED,
+};
+pub use dusk_pki::{PublicSpendKey, SecretSpendKey, StealthAddress};
+
+#[cfg(all(feature = "proof", feature = "canon"))]
+pub use crate::{
+    prove_blind_bid, verify_blind_bid, BidLeaf, BlindBidCircuit, BlindBidKeys,
+    BlindBidPublicInputs, ProofContext, BID_TREE_DEPTH,
+};
+#[cfg(all(feature = "proof", feature = "canon"))]
+pub use dusk_plonk::prelude::{
+    Circuit, Proof, ProverKey, PublicParameters, VerifierKey,
+};
+#[cfg(all(feature = "proof", feature = "canon"))]
+pub use dusk_poseidon::tree::PoseidonBranch;
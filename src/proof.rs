@@ -60,30 +60,97 @@
 //! automatically result in a different prover ID, and thus a failed constraint
 //! on line 1. of the Score Generation Circuit.
 
+use crate::bid::encoding::preimage_gadget;
 use crate::bid::score::Score;
-use crate::bid::{encoding::preimage_gadget, Bid};
+use crate::bid::{
+    blinder_generator, compute_prover_id, timestamp_to_scalar, value_generator,
+    Bid, DOMAIN_HASHED_SECRET, DOMAIN_PROVER_ID,
+};
+use crate::errors::BlindBidError;
+use crate::hasher::{BidHasher, DefaultHasher};
 use anyhow::Result;
 use dusk_bls12_381::BlsScalar;
-use dusk_jubjub::{JubJubAffine, GENERATOR_EXTENDED, GENERATOR_NUMS_EXTENDED};
-use dusk_pki::Ownable;
+use dusk_jubjub::JubJubAffine;
 use dusk_plonk::constraint_system::ecc::{
     scalar_mul::fixed_base::scalar_mul, Point,
 };
 use dusk_plonk::prelude::*;
-use dusk_poseidon::{
-    sponge,
-    tree::{merkle_opening as merkle_opening_gadget, PoseidonBranch},
+use dusk_poseidon::tree::{
+    merkle_opening as merkle_opening_gadget, PoseidonBranch,
 };
 use plonk_gadgets::{AllocatedScalar, RangeGadgets::max_bound};
+use sparse::sparse_opening_gadget;
+
+pub(crate) mod batch;
 #[cfg(test)]
 mod bid_tests;
-#[cfg(test)]
-mod tree_assets;
+pub(crate) mod bounds;
+pub(crate) mod bundle;
+pub(crate) mod candidate;
+pub(crate) mod context;
+pub(crate) mod keys;
+#[cfg(feature = "mock")]
+pub(crate) mod mock;
+pub(crate) mod prove;
+pub(crate) mod public_inputs;
+pub(crate) mod service;
+pub(crate) mod sparse;
+pub(crate) mod threshold;
+#[cfg(feature = "canon")]
+pub(crate) mod tree_assets;
+pub(crate) mod verify;
+pub(crate) mod witness;
+
+pub use bounds::ValueBoundsCircuit;
+pub use bundle::BlindBidVerifierBundle;
+pub use candidate::validate_candidate_set;
+pub use context::{ProofContext, BLINDBID_TRANSCRIPT_LABEL};
+pub use keys::BlindBidKeys;
+#[cfg(feature = "mock")]
+pub use mock::{verify_mock_proof, MockProof};
+pub use prove::prove_blind_bid;
+pub use public_inputs::{reconstruct_public_inputs, BlindBidPublicInputs};
+pub use service::{BlindBidProver, BlindBidVerifier};
+pub use sparse::{BidOpening, SparseBidTree, SparseBranch};
+pub use threshold::ThresholdCircuit;
+#[cfg(feature = "canon")]
+pub use tree_assets::{BidLeaf, BidTree, BID_TREE_CAPACITY};
+pub use verify::{
+    verify_blind_bid, verify_blind_bid_bytes, verify_blind_bids_batch,
+    VERIFY_ERR_INVALID_PROOF, VERIFY_ERR_PROOF, VERIFY_ERR_PUBLIC_INPUTS,
+    VERIFY_ERR_PUB_PARAMS, VERIFY_ERR_VERIFIER_KEY, VERIFY_OK,
+};
+pub use witness::BidWitness;
+
+/// Trim size of the Public Parameters used to verify the proofs generated
+/// with the [`BlindBidCircuit`].
+pub(crate) const TRIM_SIZE: usize = 1 << 15;
+
+/// Depth of the Bid tree that the proofs verified with
+/// [`verify_blind_bid`] and the keys compiled with [`BlindBidKeys`] are
+/// generated for.
+pub const BID_TREE_DEPTH: usize = 17;
 
 /// The BlindBidCircuit is used to prove or verify a proof of blindbid.
 /// As the [Circuit](dusk_plonk::circuit_builder::Circuit) shows. The circuit
 /// needs to be previously compiled.
 ///
+/// The circuit is generic over the `DEPTH` of the Bid tree, so the Merkle
+/// Opening only takes the constraints needed by the actual tree height. Since
+/// the depth is part of the type, a branch of a different depth is rejected
+/// when the circuit is built, and the keys are only valid for the depth of
+/// the circuit they were compiled from.
+///
+/// The Merkle Opening proves that the hash of the Bid is a leaf of either a
+/// dense tree or a [`SparseBidTree`], see [`BidOpening`]. In a dense tree the
+/// `pos` of the Bid is its insertion index and is not checked against the
+/// path of the branch. In a sparse tree the path is constrained to the key
+/// derived from the hash of the Bid, and `pos` is just part of its data.
+/// Proofs of sparse openings are verified with [`Circuit::verify_proof`] on
+/// a circuit built with [`BlindBidCircuit::for_keygen`] and a
+/// [`SparseBranch::default`], since [`verify_blind_bid`], [`BlindBidKeys`]
+/// and [`BlindBidVerifierBundle`] expect dense openings.
+///
 /// The [Public Inputs](dusk_plonk::circuit_builder::PublicInput) **in order**
 /// used for the proof are:
 /// 1. Merkle Tree Root.
@@ -94,36 +161,38 @@ mod tree_assets;
 ///
 /// # Example
 /// ```ignore
-/// // Initialize your `PublicInput` Vector.
-/// let pi = vec![
-///     PublicInput::BlsScalar(*branch.root(), 0),
-///     PublicInput::BlsScalar(storage_bid, 0),
-///     PublicInput::AffinePoint(bid.commitment(), 0, 0),
-///     PublicInput::BlsScalar(bid.hashed_secret(), 0),
-///     PublicInput::BlsScalar(prover_id, 0),
-///     PublicInput::BlsScalar(score.value(), 0)];
+/// // Initialize your `PublicInput` Vector in the canonical order.
+/// let pi = BlindBidPublicInputs {
+///     root: *branch.root(),
+///     bid_hash: bid.hash(),
+///     commitment: bid.commitment(),
+///     hashed_secret: bid.hashed_secret(),
+///     prover_id,
+///     score: score.value(),
+/// }
+/// .to_public_inputs();
 ///
 /// // Create a mutable instance of the BlindBidCircuit and
 /// //
 /// let mut circuit = BlindBidCircuit {
-///     bid,
+///     bid: BidWitness::default(),
 ///     score: Score::default(),
 ///     secret_k: BlsScalar::one(),
-///     secret: JubJubAffine::default(),
 ///     seed: BlsScalar::from(consensus_round_seed),
 ///     latest_consensus_round: BlsScalar::from(latest_consensus_round),
 ///     latest_consensus_step: BlsScalar::from(latest_consensus_step),
-///     branch: &branch,
+///     branch: BidOpening::Dense(&branch),
 ///     trim_size: 1 << 15,
+///     private_commitment: false,
 ///     pi_positions: vec![],
 /// };
 /// circuit.verify_proof(&pub_params, &vk, b"CorrectBid", &proof, &pi)
 /// ```
-#[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "canon"))))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "proof", feature = "canon"))))]
 #[derive(Debug, Clone)]
-pub struct BlindBidCircuit<'a> {
-    /// Bid used to generate the score
-    pub bid: Bid,
+pub struct BlindBidCircuit<'a, const DEPTH: usize> {
+    /// Witness of the Bid used to generate the score
+    pub bid: BidWitness,
     /// Score generated from the previous Bid.
     pub score: Score,
     /// Pre-image of the hashed_secret of the Bid.
@@ -135,16 +204,167 @@ pub struct BlindBidCircuit<'a> {
     /// Latest consensus step value at which Score was generated.
     pub latest_consensus_step: BlsScalar,
     /// Merkle Opening of the leaf that contains the Bid.
-    pub branch: &'a PoseidonBranch<17>,
-    /// Secret that derypts the Cipher.
-    pub secret: JubJubAffine,
+    pub branch: BidOpening<'a, DEPTH>,
     /// Trim size of the Public Parameters used by the PLONK mechanism.
     pub trim_size: usize,
+    /// Whether the commitment of the Bid is kept as a private witness
+    /// instead of being a Public Input. See
+    /// [`BlindBidCircuit::with_private_commitment`].
+    pub private_commitment: bool,
     /// Positions of the Public Inputs used with the proof.
     pub pi_positions: Vec<PublicInput>,
 }
 
-impl<'a> Circuit<'a> for BlindBidCircuit<'a> {
+impl<'a, const DEPTH: usize> BlindBidCircuit<'a, DEPTH> {
+    /// Fills the circuit to prove `bid` with its `score` at the given
+    /// consensus round and step.
+    ///
+    /// The trim size is set to the one [`verify_blind_bid`] expects and can
+    /// be overridden afterwards with [`BlindBidCircuit::with_trim_size`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_inputs(
+        bid: &Bid,
+        score: Score,
+        secret: &JubJubAffine,
+        secret_k: BlsScalar,
+        branch: impl Into<BidOpening<'a, DEPTH>>,
+        seed: BlsScalar,
+        latest_consensus_round: u64,
+        latest_consensus_step: u64,
+    ) -> Self {
+        BlindBidCircuit {
+            bid: BidWitness::new(bid, secret),
+            score,
+            secret_k,
+            seed,
+            latest_consensus_round: BlsScalar::from(latest_consensus_round),
+            latest_consensus_step: BlsScalar::from(latest_consensus_step),
+            branch: branch.into(),
+            trim_size: TRIM_SIZE,
+            private_commitment: false,
+            pi_positions: vec![],
+        }
+    }
+
+    /// Fills the circuit with placeholder witnesses, to compile it for a tree
+    /// of the same depth as `branch` and the Public Parameters trimmed to
+    /// `trim_size`.
+    ///
+    /// The keys of the circuit only depend on its structure, so they are the
+    /// same ones obtained by compiling a circuit filled with real witnesses.
+    pub fn for_keygen(
+        branch: impl Into<BidOpening<'a, DEPTH>>,
+        trim_size: usize,
+    ) -> Self {
+        BlindBidCircuit {
+            bid: BidWitness::default(),
+            score: Score::default(),
+            secret_k: BlsScalar::one(),
+            seed: BlsScalar::zero(),
+            latest_consensus_round: BlsScalar::zero(),
+            latest_consensus_step: BlsScalar::zero(),
+            branch: branch.into(),
+            trim_size,
+            private_commitment: false,
+            pi_positions: vec![],
+        }
+    }
+
+    /// Keeps the commitment of the Bid as a private witness of the circuit,
+    /// removing it from the Public Inputs, which then have to be built with
+    /// [`BlindBidPublicInputs::to_public_inputs_without_commitment`].
+    ///
+    /// The proof still binds the commitment through the hash of the Bid,
+    /// which is a Public Input: only a Bid with the same commitment opens
+    /// the same leaf of the Bid tree. What is lost is the direct binding to
+    /// a known commitment: verifiers that don't know the Bid can't tell
+    /// which commitment (and therefore which stake) the proof was generated
+    /// for. The keys of this mode differ from the ones of the default one.
+    ///
+    /// Only the default mode is supported by [`verify_blind_bid`],
+    /// [`BlindBidKeys`] and [`BlindBidVerifierBundle`]: proofs of this mode
+    /// are verified with [`Circuit::verify_proof`] on a circuit built with
+    /// [`BlindBidCircuit::for_keygen`] and this method.
+    pub fn with_private_commitment(mut self) -> Self {
+        self.private_commitment = true;
+        self
+    }
+
+    /// Sets the trim size of the Public Parameters used by the circuit.
+    pub fn with_trim_size(mut self, trim_size: usize) -> Self {
+        self.trim_size = trim_size;
+        self
+    }
+
+    /// Returns the number of constraints (gates) the circuit generates.
+    ///
+    /// The count only depends on the `DEPTH` of the tree, on the kind of
+    /// [`BidOpening`] and on whether the commitment is kept private, so it
+    /// is obtained by running the gadget on a circuit filled with placeholder
+    /// witnesses, without compiling it nor touching the witnesses of `self`.
+    /// The Public Parameters need a degree and a trim size of at least the
+    /// next power of two of it.
+    ///
+    /// A failure of the gadget is reported as
+    /// [`BlindBidError::CircuitSynthesisFailed`].
+    pub fn constraint_count(&self) -> Result<usize, BlindBidError> {
+        let mut circuit =
+            BlindBidCircuit::<'_, DEPTH>::for_keygen(self.branch, TRIM_SIZE);
+        circuit.private_commitment = self.private_commitment;
+        let mut composer = StandardComposer::with_expected_size(TRIM_SIZE);
+        circuit
+            .gadget(&mut composer)
+            .map_err(|_| BlindBidError::CircuitSynthesisFailed)?;
+        Ok(composer.circuit_size())
+    }
+
+    /// Returns the minimum trim size the circuit fits in with a dense
+    /// opening: the smallest power of two that is not lower than its
+    /// [`BlindBidCircuit::constraint_count`].
+    ///
+    /// The trim size used by [`verify_blind_bid`] is never lower than the
+    /// one of trees of [`BID_TREE_DEPTH`].
+    pub fn recommended_trim_size() -> Result<usize, BlindBidError> {
+        let branch = PoseidonBranch::<DEPTH>::default();
+        BlindBidCircuit::<'_, DEPTH>::for_keygen(&branch, TRIM_SIZE)
+            .constraint_count()
+            .map(usize::next_power_of_two)
+    }
+
+    /// Checks that the trim size of the circuit is not lower than the
+    /// smallest power of two that fits its
+    /// [`BlindBidCircuit::constraint_count`], which is the
+    /// [`BlindBidCircuit::recommended_trim_size`] of dense openings, returning
+    /// [`BlindBidError::TrimSizeTooSmall`] otherwise, and that the Public
+    /// Parameters can be trimmed to it, returning
+    /// [`BlindBidError::TrimSizeTooLarge`] otherwise.
+    ///
+    /// [`BlindBidKeys::compile`] and
+    /// [`BlindBidCircuit::export_verifier_bundle`] perform this check before
+    /// compiling the circuit.
+    pub fn check_trim_size(
+        &self,
+        pub_params: &PublicParameters,
+    ) -> Result<(), BlindBidError> {
+        let minimum = self.constraint_count()?.next_power_of_two();
+        if self.trim_size < minimum {
+            return Err(BlindBidError::TrimSizeTooSmall {
+                requested: self.trim_size,
+                minimum,
+            });
+        }
+        let available = pub_params.max_degree();
+        if self.trim_size > available {
+            return Err(BlindBidError::TrimSizeTooLarge {
+                requested: self.trim_size,
+                available,
+            });
+        }
+        Ok(())
+    }
+}
+
+impl<'a, const DEPTH: usize> Circuit<'a> for BlindBidCircuit<'a, DEPTH> {
     fn gadget(&mut self, composer: &mut StandardComposer) -> Result<()> {
         // Check if the inputs were indeed pre-loaded inside of the circuit
         // structure.
@@ -155,43 +375,37 @@ impl<'a> Circuit<'a> for BlindBidCircuit<'a> {
         let latest_consensus_round = self.latest_consensus_round;
         let latest_consensus_step = self.latest_consensus_step;
         let score = self.score;
-        let secret = self.secret;
+        let private_commitment = self.private_commitment;
         // Instantiate PI vector.
         let pi = self.get_mut_pi_positions();
         // Get the corresponding `StorageBid` value that for the `Bid`
         // which is effectively the value of the proven leaf (hash of the Bid)
         // and allocate it.
-        let storage_bid: BlsScalar = bid.into();
-        let bid_hash = AllocatedScalar::allocate(composer, storage_bid);
+        let bid_hash = AllocatedScalar::allocate(composer, bid.hash);
         // Allocate Bid-internal fields
         let bid_hashed_secret =
-            AllocatedScalar::allocate(composer, bid.hashed_secret());
+            AllocatedScalar::allocate(composer, bid.hashed_secret);
         let bid_cipher = (
-            composer.add_input(bid.encrypted_data().cipher()[0]),
-            composer.add_input(bid.encrypted_data().cipher()[1]),
+            composer.add_input(bid.cipher[0]),
+            composer.add_input(bid.cipher[1]),
         );
         let bid_commitment =
-            Point::from_private_affine(composer, bid.commitment());
+            Point::from_private_affine(composer, bid.commitment);
         let bid_stealth_addr = (
-            Point::from_private_affine(
-                composer,
-                bid.stealth_address().pk_r().as_ref().into(),
-            ),
-            Point::from_private_affine(
-                composer,
-                bid.stealth_address().R().into(),
-            ),
+            Point::from_private_affine(composer, bid.pk_r),
+            Point::from_private_affine(composer, bid.R),
         );
+        // Same conversion as `Bid::eligibility_scalar` and
+        // `Bid::expiration_scalar`.
         let bid_eligibility_ts = AllocatedScalar::allocate(
             composer,
-            BlsScalar::from(bid.eligibility()),
+            timestamp_to_scalar(bid.eligibility),
         );
         let bid_expiration = AllocatedScalar::allocate(
             composer,
-            BlsScalar::from(bid.expiration()),
+            timestamp_to_scalar(bid.expiration),
         );
-        let pos =
-            AllocatedScalar::allocate(composer, BlsScalar::from(bid.pos()));
+        let pos = AllocatedScalar::allocate(composer, BlsScalar::from(bid.pos));
         // Allocate bid-needed inputs
         let secret_k = AllocatedScalar::allocate(composer, secret_k);
         let seed = AllocatedScalar::allocate(composer, seed);
@@ -199,18 +413,11 @@ impl<'a> Circuit<'a> for BlindBidCircuit<'a> {
             AllocatedScalar::allocate(composer, latest_consensus_step);
         let latest_consensus_round =
             AllocatedScalar::allocate(composer, latest_consensus_round);
-        // Decrypt the cypher using the secret and allocate value & blinder.
-        // If the decryption fails, we just set the result to an
-        // impossible-to-obtain value.
-        // On that way, verifiers do not get stuck on the process (they don't
-        // care) about the real values here (just about filling the
-        // composer). And provers won't get any info about if this
-        // secret can or not decrypt the cipher.
-        let (value, blinder) = bid
-            .decrypt_data(&secret)
-            .unwrap_or((JubJubScalar::one(), JubJubScalar::one()));
-        let bid_value = AllocatedScalar::allocate(composer, value.into());
-        let bid_blinder = AllocatedScalar::allocate(composer, blinder.into());
+        // Allocate value & blinder. Verifiers do not care about the real
+        // values here (just about filling the composer).
+        let bid_value = AllocatedScalar::allocate(composer, bid.value.into());
+        let bid_blinder =
+            AllocatedScalar::allocate(composer, bid.blinder.into());
         // Allocate the bid tree root to be used later by the score_generation
         // gadget.
         let bid_tree_root =
@@ -223,7 +430,17 @@ impl<'a> Circuit<'a> for BlindBidCircuit<'a> {
         // ------------------------------------------------------- //
 
         // 1. Merkle Opening
-        let root = merkle_opening_gadget(composer, branch, bid_hash.var);
+        let root = match branch {
+            BidOpening::Dense(branch) => {
+                merkle_opening_gadget(composer, branch, bid_hash.var)
+            }
+            BidOpening::Sparse(branch) => sparse_opening_gadget(
+                composer,
+                branch,
+                bid_hash.var,
+                bid_hash.scalar,
+            ),
+        };
         // Add PI constraint for the root to the PI constructor
         pi.push(PublicInput::BlsScalar(
             -branch.root(),
@@ -258,6 +475,12 @@ impl<'a> Circuit<'a> for BlindBidCircuit<'a> {
             -bid_hash.scalar,
         );
 
+        // The comparisons of steps 3. & 4. only hold if all of the timestamps
+        // fit in 64 bits.
+        composer.range_gate(latest_consensus_round.var, 64usize);
+        composer.range_gate(bid_eligibility_ts.var, 64usize);
+        composer.range_gate(bid_expiration.var, 64usize);
+
         // 3. t_a >= k_t
         // k_t - t_a should be > 2^64 which is the max size of the round.
         let kt_min_ta = composer.add(
@@ -319,28 +542,41 @@ impl<'a> Circuit<'a> for BlindBidCircuit<'a> {
         );
 
         // 5. c = C(v, b) Pedersen Commitment check
-        let p1 = scalar_mul(composer, bid_value.var, GENERATOR_EXTENDED);
-        let p2 = scalar_mul(composer, bid_blinder.var, GENERATOR_NUMS_EXTENDED);
+        let p1 = scalar_mul(composer, bid_value.var, value_generator());
+        let p2 = scalar_mul(composer, bid_blinder.var, blinder_generator());
         let computed_c = p1.point().fast_add(composer, *p2.point());
-        // Add PI constraint for the commitment computation check.
-        pi.push(PublicInput::AffinePoint(
-            bid.commitment(),
-            composer.circuit_size(),
-            composer.circuit_size() + 1,
-        ));
+        if private_commitment {
+            // Assert computed_commitment == commitment of the Bid preimage.
+            // Both coordinates are constrained explicitly, since
+            // `assert_equal_point` leaves the `y` one unconstrained.
+            composer.assert_equal(*computed_c.x(), *bid_commitment.x());
+            composer.assert_equal(*computed_c.y(), *bid_commitment.y());
+        } else {
+            // Add PI constraint for the commitment computation check.
+            pi.push(PublicInput::AffinePoint(
+                bid.commitment,
+                composer.circuit_size(),
+                composer.circuit_size() + 1,
+            ));
 
-        // Assert computed_commitment == announced commitment.
-        composer.assert_equal_public_point(computed_c, bid.commitment());
+            // Assert computed_commitment == announced commitment.
+            composer.assert_equal_public_point(computed_c, bid.commitment);
+        }
 
         // 6. 0 < value <= 2^64 range check
         // v < 2^64
         composer.range_gate(bid_value.var, 64usize);
 
-        // 7. `m = H(k)` Secret key pre-image check.
-        let secret_k_hash = sponge::gadget(composer, &[secret_k.var]);
+        // 7. `m = H(DOMAIN_HASHED_SECRET, k)` Secret key pre-image check.
+        let domain_hashed_secret =
+            composer.add_witness_to_circuit_description(DOMAIN_HASHED_SECRET);
+        let secret_k_hash = DefaultHasher::gadget(
+            composer,
+            &[domain_hashed_secret, secret_k.var],
+        );
         // Add PI constraint for the secret_k_hash.
         pi.push(PublicInput::BlsScalar(
-            -bid.hashed_secret(),
+            -bid.hashed_secret,
             composer.circuit_size(),
         ));
 
@@ -349,15 +585,19 @@ impl<'a> Circuit<'a> for BlindBidCircuit<'a> {
         composer.constrain_to_constant(
             secret_k_hash,
             BlsScalar::zero(),
-            -bid.hashed_secret(),
+            -bid.hashed_secret,
         );
 
         // We generate the prover_id and constrain it to a public input
         // On that way we bind the Score to the correct id.
-        // 8. `prover_id = H(secret_k, sigma^s, k^t, k^s)`. Preimage check
-        let prover_id = sponge::gadget(
+        // 8. `prover_id = H(DOMAIN_PROVER_ID, secret_k, sigma^s, k^t, k^s)`.
+        // Preimage check
+        let domain_prover_id =
+            composer.add_witness_to_circuit_description(DOMAIN_PROVER_ID);
+        let prover_id = DefaultHasher::gadget(
             composer,
             &[
+                domain_prover_id,
                 secret_k.var,
                 seed.var,
                 latest_consensus_round.var,
@@ -367,24 +607,20 @@ impl<'a> Circuit<'a> for BlindBidCircuit<'a> {
 
         // Constraint the prover_id to be the public one and set it in the PI
         // constructor.
+        let prover_id_scalar = compute_prover_id(
+            secret_k.scalar,
+            seed.scalar,
+            latest_consensus_round.scalar,
+            latest_consensus_step.scalar,
+        );
         pi.push(PublicInput::BlsScalar(
-            -bid.generate_prover_id(
-                secret_k.scalar,
-                seed.scalar,
-                latest_consensus_round.scalar,
-                latest_consensus_step.scalar,
-            ),
+            -prover_id_scalar,
             composer.circuit_size(),
         ));
         composer.constrain_to_constant(
             prover_id,
             BlsScalar::zero(),
-            -bid.generate_prover_id(
-                secret_k.scalar,
-                seed.scalar,
-                latest_consensus_round.scalar,
-                latest_consensus_step.scalar,
-            ),
+            -prover_id_scalar,
         );
 
         // 9. Score generation circuit check with the corresponding gadget.
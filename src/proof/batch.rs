@@ -0,0 +1,61 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Generation of several Blind Bid proofs sharing the same setup.
+
+use super::BlindBidCircuit;
+use anyhow::{anyhow, Result};
+use dusk_plonk::prelude::*;
+
+impl<'a, const DEPTH: usize> BlindBidCircuit<'a, DEPTH> {
+    /// Generates a proof for each one of the provided `circuits`, which are
+    /// expected to be already filled with the witnesses of the Bids they
+    /// prove.
+    ///
+    /// Compared to calling
+    /// [gen_proof](dusk_plonk::circuit_builder::Circuit::gen_proof) once per
+    /// circuit, the Public Parameters are trimmed only once and the same
+    /// [Prover] is reused for all of the proofs. The FFTs and commitments of
+    /// every proof are still computed on their own, so the cost of the
+    /// proving itself is the same as the one of the single proofs.
+    ///
+    /// The Bids are provided as circuits rather than as `(Bid, Score, ..)`
+    /// tuples because every proof also needs the branch, the secrets and the
+    /// consensus parameters of its Bid, and the circuit borrows the branch.
+    /// They can be built with [`BlindBidCircuit::from_inputs`].
+    ///
+    /// All of the circuits must share the same `trim_size`, since they're
+    /// proved with the same `prover_key`.
+    pub fn gen_proofs_batch(
+        pub_params: &PublicParameters,
+        prover_key: &ProverKey,
+        circuits: &mut [BlindBidCircuit<'a, DEPTH>],
+        label: &'static [u8],
+    ) -> Result<Vec<Proof>> {
+        let trim_size = match circuits.first() {
+            Some(circuit) => circuit.get_trim_size(),
+            None => return Ok(vec![]),
+        };
+        if circuits.iter().any(|c| c.get_trim_size() != trim_size) {
+            return Err(anyhow!(
+                "All of the circuits of a batch must share the same trim size"
+            ));
+        }
+
+        let (ck, _) = pub_params.trim(trim_size)?;
+        let mut prover = Prover::with_expected_size(label, trim_size);
+        prover.prover_key = Some(prover_key.clone());
+
+        circuits
+            .iter_mut()
+            .map(|circuit| {
+                prover.clear_witness();
+                circuit.gadget(prover.mut_cs())?;
+                prover.prove(&ck)
+            })
+            .collect()
+    }
+}
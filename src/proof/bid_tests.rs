@@ -7,11 +7,18 @@
 #![allow(non_snake_case)]
 
 use super::tree_assets::BidTree;
-use crate::{Bid, BlindBidCircuit, BlindBidError, Score, V_RAW_MAX, V_RAW_MIN};
+use crate::{
+    reconstruct_public_inputs, timestamp_to_scalar, validate_candidate_set,
+    verify_blind_bid, verify_blind_bid_bytes, Bid, BidOpening, BidWitness,
+    BlindBidCircuit, BlindBidError, BlindBidPublicInputs,
+    BlindBidVerifierBundle, CandidateRejection, ProofContext, Score,
+    VERIFY_ERR_INVALID_PROOF, VERIFY_ERR_PUBLIC_INPUTS,
+    VERIFY_ERR_VERIFIER_KEY, VERIFY_OK, V_RAW_MAX, V_RAW_MIN,
+};
 use anyhow::Result;
 use canonical_host::MemStore;
 use dusk_bytes::Serializable;
-use dusk_pki::{PublicSpendKey, SecretSpendKey};
+use dusk_pki::{Ownable, PublicSpendKey, SecretSpendKey};
 use dusk_plonk::jubjub::{JubJubAffine, GENERATOR_EXTENDED};
 use dusk_plonk::prelude::*;
 use rand::Rng;
@@ -43,6 +50,57 @@ fn random_bid(secret: &JubJubScalar, secret_k: BlsScalar) -> Bid {
     .expect("Bid creation error")
 }
 
+const ROUND: u64 = 50u64;
+const STEP: u64 = 50u64;
+
+/// Generates a proof for the `Bid` stored at `idx` of the tree at `round`
+/// and `STEP`.
+fn prove(
+    pub_params: &PublicParameters,
+    tree: &BidTree<MemStore>,
+    idx: usize,
+    secret: JubJubAffine,
+    secret_k: BlsScalar,
+    seed: BlsScalar,
+    round: u64,
+    label: &'static [u8],
+) -> Result<(Bid, Proof, BlindBidPublicInputs, VerifierKey)> {
+    let bid = tree.get(idx as u64)?.expect("Bid not found").bid();
+    let branch = tree
+        .poseidon_branch(idx as u64)?
+        .expect("Poseidon Branch Extraction");
+    let score = Score::compute(
+        &bid,
+        &secret,
+        secret_k,
+        *branch.root(),
+        seed,
+        round,
+        STEP,
+    )
+    .expect("Score computation error");
+    let prover_id = bid.generate_prover_id(
+        secret_k,
+        seed,
+        BlsScalar::from(round),
+        BlsScalar::from(STEP),
+    );
+
+    let mut circuit = BlindBidCircuit::from_inputs(
+        &bid, score, &secret, secret_k, &branch, seed, round, STEP,
+    );
+    let (pk, vk) = circuit.compile(pub_params)?;
+    let proof = circuit.gen_proof(pub_params, &pk, label)?;
+    let pi = BlindBidPublicInputs::from_bid(
+        &bid,
+        *branch.root(),
+        score.value(),
+        prover_id,
+    );
+
+    Ok((bid, proof, pi, vk))
+}
+
 #[cfg(test)]
 mod protocol_tests {
     use super::*;
@@ -66,11 +124,11 @@ mod protocol_tests {
         let latest_consensus_step = 50u64;
 
         // Append the Bid to the tree.
-        tree.push(bid.into());
+        tree.push(bid.into())?;
 
         // Extract the branch
         let branch = tree
-            .poseidon_branch(0usize)
+            .poseidon_branch(0)?
             .expect("Poseidon Branch Extraction");
 
         // Generate a `Score` for our Bid with the consensus parameters
@@ -93,15 +151,15 @@ mod protocol_tests {
         );
 
         let mut circuit = BlindBidCircuit {
-            bid,
+            bid: BidWitness::new(&bid, &secret),
             score,
             secret_k,
-            secret,
             seed: BlsScalar::from(consensus_round_seed),
             latest_consensus_round: BlsScalar::from(latest_consensus_round),
             latest_consensus_step: BlsScalar::from(latest_consensus_step),
-            branch: &branch,
+            branch: BidOpening::Dense(&branch),
             trim_size: 1 << 15,
+            private_commitment: false,
             pi_positions: vec![],
         };
 
@@ -109,31 +167,211 @@ mod protocol_tests {
             .compile(&pub_params)
             .expect("Circuit compilation Error");
         let proof = circuit.gen_proof(&pub_params, &pk, b"CorrectBid")?;
-        let storage_bid = bid.hash();
-        let pi = vec![
-            PublicInput::BlsScalar(*branch.root(), 0),
-            PublicInput::BlsScalar(storage_bid, 0),
-            PublicInput::AffinePoint(bid.commitment(), 0, 0),
-            PublicInput::BlsScalar(bid.hashed_secret(), 0),
-            PublicInput::BlsScalar(prover_id, 0),
-            PublicInput::BlsScalar(score.value(), 0),
-        ];
+        let pi = BlindBidPublicInputs {
+            root: *branch.root(),
+            bid_hash: bid.hash(),
+            commitment: bid.commitment(),
+            hashed_secret: bid.hashed_secret(),
+            prover_id,
+            score: score.value(),
+        }
+        .to_public_inputs();
 
         let mut circuit = BlindBidCircuit {
-            bid,
+            bid: BidWitness::default(),
             score: Score::default(),
             secret_k: BlsScalar::one(),
-            secret: JubJubAffine::default(),
             seed: BlsScalar::from(consensus_round_seed),
             latest_consensus_round: BlsScalar::from(latest_consensus_round),
             latest_consensus_step: BlsScalar::from(latest_consensus_step),
-            branch: &branch,
+            branch: BidOpening::Dense(&branch),
             trim_size: 1 << 15,
+            private_commitment: false,
             pi_positions: vec![],
         };
         circuit.verify_proof(&pub_params, &vk, b"CorrectBid", &proof, &pi)
     }
 
+    #[test]
+    fn private_commitment_proof() -> Result<()> {
+        let pub_params =
+            PublicParameters::setup(1 << 17, &mut rand::thread_rng())?;
+        let mut tree = BidTree::<MemStore>::new();
+        let secret = JubJubScalar::random(&mut rand::thread_rng());
+        let secret_k = BlsScalar::random(&mut rand::thread_rng());
+        let (_, branch) = tree.push_bid(random_bid(&secret, secret_k))?;
+        let bid = tree.get(0)?.expect("Bid not found").bid();
+        let secret: JubJubAffine = (GENERATOR_EXTENDED * &secret).into();
+        let seed = BlsScalar::random(&mut rand::thread_rng());
+
+        let score = Score::compute(
+            &bid,
+            &secret,
+            secret_k,
+            *branch.root(),
+            seed,
+            ROUND,
+            STEP,
+        )
+        .expect("Score computation error");
+        let prover_id = bid.generate_prover_id(
+            secret_k,
+            seed,
+            BlsScalar::from(ROUND),
+            BlsScalar::from(STEP),
+        );
+
+        let mut circuit = BlindBidCircuit::from_inputs(
+            &bid, score, &secret, secret_k, &branch, seed, ROUND, STEP,
+        )
+        .with_private_commitment();
+        let (pk, vk) = circuit.compile(&pub_params)?;
+        let proof =
+            circuit.gen_proof(&pub_params, &pk, b"PrivateCommitment")?;
+        let pi = BlindBidPublicInputs::from_bid(
+            &bid,
+            *branch.root(),
+            score.value(),
+            prover_id,
+        );
+
+        let mut verifier = BlindBidCircuit::for_keygen(&branch, 1 << 15)
+            .with_private_commitment();
+        verifier.verify_proof(
+            &pub_params,
+            &vk,
+            b"PrivateCommitment",
+            &proof,
+            &pi.to_public_inputs_without_commitment(),
+        )?;
+        assert!(verifier
+            .verify_proof(
+                &pub_params,
+                &vk,
+                b"PrivateCommitment",
+                &proof,
+                &pi.to_public_inputs(),
+            )
+            .is_err());
+
+        // The bundle only supports the default mode.
+        assert!(matches!(
+            BlindBidCircuit::for_keygen(&branch, 1 << 15)
+                .with_private_commitment()
+                .export_verifier_bundle(&pub_params, b"PrivateCommitment"),
+            Err(BlindBidError::PrivateCommitmentUnsupported)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn private_commitment_with_wrong_y() -> Result<()> {
+        let pub_params =
+            PublicParameters::setup(1 << 17, &mut rand::thread_rng())?;
+        let secret = JubJubScalar::random(&mut rand::thread_rng());
+        let secret_k = BlsScalar::random(&mut rand::thread_rng());
+        let bid = random_bid(&secret, secret_k);
+        let secret: JubJubAffine = (GENERATOR_EXTENDED * &secret).into();
+        let seed = BlsScalar::random(&mut rand::thread_rng());
+
+        // Same Bid with the `y` coordinate of the commitment negated, which
+        // is still a point of the curve. Since the Bid stored in the tree
+        // carries it, only the commitment check can tell it apart.
+        let c = bid.commitment();
+        let tampered = Bid::from_parts(
+            bid.encrypted_data(),
+            bid.nonce(),
+            *bid.stealth_address(),
+            bid.hashed_secret(),
+            JubJubAffine::from_raw_unchecked(c.get_x(), -c.get_y()),
+            bid.eligibility(),
+            bid.expiration(),
+            0,
+        );
+        let mut tree = BidTree::<MemStore>::new();
+        let (_, branch) = tree.push_bid(tampered)?;
+        let tampered = tree.get(0)?.expect("Bid not found").bid();
+
+        let score = Score::compute(
+            &tampered,
+            &secret,
+            secret_k,
+            *branch.root(),
+            seed,
+            ROUND,
+            STEP,
+        )
+        .expect("Score computation error");
+        let prover_id = tampered.generate_prover_id(
+            secret_k,
+            seed,
+            BlsScalar::from(ROUND),
+            BlsScalar::from(STEP),
+        );
+
+        let (pk, vk) = BlindBidCircuit::for_keygen(&branch, 1 << 15)
+            .with_private_commitment()
+            .compile(&pub_params)?;
+        let mut circuit = BlindBidCircuit::from_inputs(
+            &tampered, score, &secret, secret_k, &branch, seed, ROUND, STEP,
+        )
+        .with_private_commitment();
+        let proof = circuit.gen_proof(&pub_params, &pk, b"WrongY")?;
+        let pi = BlindBidPublicInputs::from_bid(
+            &tampered,
+            *branch.root(),
+            score.value(),
+            prover_id,
+        );
+
+        assert!(BlindBidCircuit::for_keygen(&branch, 1 << 15)
+            .with_private_commitment()
+            .verify_proof(
+                &pub_params,
+                &vk,
+                b"WrongY",
+                &proof,
+                &pi.to_public_inputs_without_commitment(),
+            )
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn reconstructed_public_inputs_proof() -> Result<()> {
+        let pub_params =
+            PublicParameters::setup(1 << 17, &mut rand::thread_rng())?;
+        let mut tree = BidTree::<MemStore>::new();
+        let secret = JubJubScalar::random(&mut rand::thread_rng());
+        let secret_k = BlsScalar::random(&mut rand::thread_rng());
+        tree.push(random_bid(&secret, secret_k).into())?;
+        let secret: JubJubAffine = (GENERATOR_EXTENDED * &secret).into();
+        let seed = BlsScalar::random(&mut rand::thread_rng());
+
+        let (bid, proof, pi, vk) = prove(
+            &pub_params,
+            &tree,
+            0,
+            secret,
+            secret_k,
+            seed,
+            ROUND,
+            b"Reconstructed",
+        )?;
+
+        // The verifier only knows the Bid and the round-specific values.
+        let pi =
+            reconstruct_public_inputs(&bid, pi.root, pi.score, pi.prover_id);
+        let branch = dusk_poseidon::tree::PoseidonBranch::<17>::default();
+        BlindBidCircuit::for_keygen(&branch, 1 << 15).verify_proof(
+            &pub_params,
+            &vk,
+            b"Reconstructed",
+            &proof,
+            &pi,
+        )
+    }
+
     #[test]
     fn edited_score_blindbid_proof() -> Result<()> {
         // Generate Composer & Public Parameters
@@ -154,11 +392,11 @@ mod protocol_tests {
         let latest_consensus_step = 50u64;
 
         // Append the Bid to the tree.
-        tree.push(bid.into());
+        tree.push(bid.into())?;
 
         // Extract the branch
         let branch = tree
-            .poseidon_branch(0usize)
+            .poseidon_branch(0)?
             .expect("Poseidon Branch Extraction");
 
         // Generate a `Score` for our Bid with the consensus parameters
@@ -184,15 +422,15 @@ mod protocol_tests {
         );
 
         let mut circuit = BlindBidCircuit {
-            bid,
+            bid: BidWitness::new(&bid, &secret),
             score,
             secret_k,
-            secret,
             seed: BlsScalar::from(consensus_round_seed),
             latest_consensus_round: BlsScalar::from(latest_consensus_round),
             latest_consensus_step: BlsScalar::from(latest_consensus_step),
-            branch: &branch,
+            branch: BidOpening::Dense(&branch),
             trim_size: 1 << 15,
+            private_commitment: false,
             pi_positions: vec![],
         };
 
@@ -201,15 +439,15 @@ mod protocol_tests {
             .expect("Circuit compilation Error");
         let proof =
             circuit.gen_proof(&pub_params, &pk, b"BidWithEditedScore")?;
-        let storage_bid = bid.hash();
-        let pi = vec![
-            PublicInput::BlsScalar(*branch.root(), 0),
-            PublicInput::BlsScalar(storage_bid, 0),
-            PublicInput::AffinePoint(bid.c, 0, 0),
-            PublicInput::BlsScalar(bid.hashed_secret(), 0),
-            PublicInput::BlsScalar(prover_id, 0),
-            PublicInput::BlsScalar(score.value(), 0),
-        ];
+        let pi = BlindBidPublicInputs {
+            root: *branch.root(),
+            bid_hash: bid.hash(),
+            commitment: bid.commitment(),
+            hashed_secret: bid.hashed_secret(),
+            prover_id,
+            score: score.value(),
+        }
+        .to_public_inputs();
         assert!(circuit
             .verify_proof(&pub_params, &vk, b"BidWithEditedScore", &proof, &pi)
             .is_err());
@@ -228,7 +466,7 @@ mod protocol_tests {
         // Generate a correct Bid
         let secret = JubJubScalar::random(&mut rand::thread_rng());
         let secret_k = BlsScalar::random(&mut rand::thread_rng());
-        let mut bid = random_bid(&secret, secret_k);
+        let bid = random_bid(&secret, secret_k);
         let secret: JubJubAffine = (GENERATOR_EXTENDED * &secret).into();
         // Generate fields for the Bid & required by the compute_score
         let bid_tree_root = BlsScalar::random(&mut rand::thread_rng());
@@ -237,11 +475,11 @@ mod protocol_tests {
         let latest_consensus_step = 25519u64;
 
         // Append the Bid to the tree.
-        tree.push(bid.into());
+        tree.push(bid.into())?;
 
         // Extract the branch
         let branch = tree
-            .poseidon_branch(0usize)
+            .poseidon_branch(0)?
             .expect("Poseidon Branch Extraction");
 
         // Generate a `Score` for our Bid with the consensus parameters
@@ -264,18 +502,27 @@ mod protocol_tests {
         );
 
         // Edit the Bid in order to cheat and get a bigger Score/whatever.
-        bid.hashed_secret = BlsScalar::from(63463245u64);
+        let bid = Bid::from_parts(
+            bid.encrypted_data(),
+            bid.nonce(),
+            *bid.stealth_address(),
+            BlsScalar::from(63463245u64),
+            bid.commitment(),
+            bid.eligibility(),
+            bid.expiration(),
+            bid.pos(),
+        );
 
         let mut circuit = BlindBidCircuit {
-            bid,
+            bid: BidWitness::new(&bid, &secret),
             score,
             secret_k,
-            secret,
             seed: BlsScalar::from(consensus_round_seed),
             latest_consensus_round: BlsScalar::from(latest_consensus_round),
             latest_consensus_step: BlsScalar::from(latest_consensus_step),
-            branch: &branch,
+            branch: BidOpening::Dense(&branch),
             trim_size: 1 << 15,
+            private_commitment: false,
             pi_positions: vec![],
         };
 
@@ -283,15 +530,15 @@ mod protocol_tests {
             .compile(&pub_params)
             .expect("Circuit compilation Error");
         let proof = circuit.gen_proof(&pub_params, &pk, b"EditedBidValue")?;
-        let storage_bid = bid.hash();
-        let pi = vec![
-            PublicInput::BlsScalar(*branch.root(), 0),
-            PublicInput::BlsScalar(storage_bid, 0),
-            PublicInput::AffinePoint(bid.c, 0, 0),
-            PublicInput::BlsScalar(bid.hashed_secret(), 0),
-            PublicInput::BlsScalar(prover_id, 0),
-            PublicInput::BlsScalar(score.value(), 0),
-        ];
+        let pi = BlindBidPublicInputs {
+            root: *branch.root(),
+            bid_hash: bid.hash(),
+            commitment: bid.commitment(),
+            hashed_secret: bid.hashed_secret(),
+            prover_id,
+            score: score.value(),
+        }
+        .to_public_inputs();
         assert!(circuit
             .verify_proof(&pub_params, &vk, b"EditedBidValue", &proof, &pi)
             .is_err());
@@ -325,17 +572,29 @@ mod protocol_tests {
             &value,
             &secret.into(),
             secret_k,
-            elegibility_ts,
-            expiration_ts,
+            u64::MAX,
+            u64::MAX,
         )
         .expect("Bid creation error");
+        // `Bid::new` rejects Bids that expire before being eligible, so the
+        // timestamps are set afterwards.
+        let bid = Bid::from_parts(
+            bid.encrypted_data(),
+            bid.nonce(),
+            *bid.stealth_address(),
+            bid.hashed_secret(),
+            bid.commitment(),
+            elegibility_ts,
+            expiration_ts,
+            0,
+        );
 
         // Append the Bid to the tree.
-        tree.push(bid.into());
+        tree.push(bid.into())?;
 
         // Extract the branch
         let branch = tree
-            .poseidon_branch(0usize)
+            .poseidon_branch(0)?
             .expect("Poseidon Branch Extraction");
 
         // We first generate the score as if the bid wasn't expired. Otherways
@@ -369,15 +628,15 @@ mod protocol_tests {
         );
 
         let mut circuit = BlindBidCircuit {
-            bid,
+            bid: BidWitness::new(&bid, &secret),
             score,
             secret_k,
-            secret,
             seed: BlsScalar::from(consensus_round_seed),
             latest_consensus_round: BlsScalar::from(latest_consensus_round),
             latest_consensus_step: BlsScalar::from(latest_consensus_step),
-            branch: &branch,
+            branch: BidOpening::Dense(&branch),
             trim_size: 1 << 15,
+            private_commitment: false,
             pi_positions: vec![],
         };
 
@@ -385,15 +644,15 @@ mod protocol_tests {
             .compile(&pub_params)
             .expect("Circuit compilation Error");
         let proof = circuit.gen_proof(&pub_params, &pk, b"ExpiredBid")?;
-        let storage_bid = bid.hash();
-        let pi = vec![
-            PublicInput::BlsScalar(*branch.root(), 0),
-            PublicInput::BlsScalar(storage_bid, 0),
-            PublicInput::AffinePoint(bid.c, 0, 0),
-            PublicInput::BlsScalar(bid.hashed_secret(), 0),
-            PublicInput::BlsScalar(prover_id, 0),
-            PublicInput::BlsScalar(score.value(), 0),
-        ];
+        let pi = BlindBidPublicInputs {
+            root: *branch.root(),
+            bid_hash: bid.hash(),
+            commitment: bid.commitment(),
+            hashed_secret: bid.hashed_secret(),
+            prover_id,
+            score: score.value(),
+        }
+        .to_public_inputs();
         assert!(circuit
             .verify_proof(&pub_params, &vk, b"ExpiredBid", &proof, &pi)
             .is_err());
@@ -419,8 +678,8 @@ mod protocol_tests {
         let value: u64 =
             (&mut rand::thread_rng()).gen_range(V_RAW_MIN, V_RAW_MAX);
         let value = JubJubScalar::from(value);
-        let expiration_ts = 100u64;
-        let elegibility_ts = 1000u64;
+        let expiration_ts = 1000u64;
+        let elegibility_ts = 100u64;
         let bid = Bid::new(
             &mut rng,
             &stealth_addr,
@@ -433,11 +692,11 @@ mod protocol_tests {
         .expect("Bid creation error");
 
         // Append the Bid to the tree.
-        tree.push(bid.into());
+        tree.push(bid.into())?;
 
         // Extract the branch
         let branch = tree
-            .poseidon_branch(0usize)
+            .poseidon_branch(0)?
             .expect("Poseidon Branch Extraction");
 
         // We first generate the score as if the bid was still eligible.
@@ -472,15 +731,15 @@ mod protocol_tests {
         let latest_consensus_round = 200u64;
 
         let mut circuit = BlindBidCircuit {
-            bid,
+            bid: BidWitness::new(&bid, &secret),
             score,
             secret_k,
-            secret,
             seed: BlsScalar::from(consensus_round_seed),
             latest_consensus_round: BlsScalar::from(latest_consensus_round),
             latest_consensus_step: BlsScalar::from(latest_consensus_step),
-            branch: &branch,
+            branch: BidOpening::Dense(&branch),
             trim_size: 1 << 15,
+            private_commitment: false,
             pi_positions: vec![],
         };
 
@@ -488,15 +747,15 @@ mod protocol_tests {
             .compile(&pub_params)
             .expect("Circuit compilation Error");
         let proof = circuit.gen_proof(&pub_params, &pk, b"NonElegibleBid")?;
-        let storage_bid = bid.hash();
-        let pi = vec![
-            PublicInput::BlsScalar(*branch.root(), 0),
-            PublicInput::BlsScalar(storage_bid, 0),
-            PublicInput::AffinePoint(bid.c, 0, 0),
-            PublicInput::BlsScalar(bid.hashed_secret(), 0),
-            PublicInput::BlsScalar(prover_id, 0),
-            PublicInput::BlsScalar(score.value(), 0),
-        ];
+        let pi = BlindBidPublicInputs {
+            root: *branch.root(),
+            bid_hash: bid.hash(),
+            commitment: bid.commitment(),
+            hashed_secret: bid.hashed_secret(),
+            prover_id,
+            score: score.value(),
+        }
+        .to_public_inputs();
         assert!(circuit
             .verify_proof(&pub_params, &vk, b"NonElegibleBid", &proof, &pi)
             .is_err());
@@ -519,3 +778,1110 @@ mod serialization_tests {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod timestamp_tests {
+    use super::*;
+
+    const LABEL: &[u8] = b"TimestampLimits";
+
+    #[test]
+    fn timestamps_at_u64_limit() -> Result<()> {
+        let pub_params =
+            PublicParameters::setup(1 << 17, &mut rand::thread_rng())?;
+        let mut tree = BidTree::<MemStore>::new();
+        let secret = JubJubScalar::random(&mut rand::thread_rng());
+        let secret_k = BlsScalar::random(&mut rand::thread_rng());
+        // Bid with both timestamps set to `u64::MAX`.
+        tree.push(random_bid(&secret, secret_k).into())?;
+        let secret: JubJubAffine = (GENERATOR_EXTENDED * &secret).into();
+        let seed = BlsScalar::random(&mut rand::thread_rng());
+
+        // The Bid is still alive at the last round before `u64::MAX`.
+        let (_, proof, pi, vk) = prove(
+            &pub_params,
+            &tree,
+            0,
+            secret,
+            secret_k,
+            seed,
+            u64::MAX - 1,
+            LABEL,
+        )?;
+        assert!(verify_blind_bid(&pub_params, &vk, LABEL, &proof, &pi).is_ok());
+
+        // But not at `u64::MAX`, where the Score can't even be computed.
+        let bid = tree.get(0)?.expect("Bid not found").bid();
+        let branch = tree
+            .poseidon_branch(0)?
+            .expect("Poseidon Branch Extraction");
+        assert!(matches!(
+            Score::compute(
+                &bid,
+                &secret,
+                secret_k,
+                *branch.root(),
+                seed,
+                u64::MAX,
+                STEP
+            ),
+            Err(BlindBidError::ExpiredBid)
+        ));
+
+        // Neither the circuit accepts it with a Score of a previous round.
+        let score = Score::compute(
+            &bid,
+            &secret,
+            secret_k,
+            *branch.root(),
+            seed,
+            u64::MAX - 1,
+            STEP,
+        )
+        .expect("Score computation error");
+        let mut circuit = BlindBidCircuit {
+            bid: BidWitness::new(&bid, &secret),
+            score,
+            secret_k,
+            seed,
+            latest_consensus_round: BlsScalar::from(u64::MAX),
+            latest_consensus_step: BlsScalar::from(STEP),
+            branch: BidOpening::Dense(&branch),
+            trim_size: 1 << 15,
+            private_commitment: false,
+            pi_positions: vec![],
+        };
+        let (pk, vk) = circuit.compile(&pub_params)?;
+        let proof = circuit.gen_proof(&pub_params, &pk, LABEL)?;
+        let pi = BlindBidPublicInputs::from_bid(
+            &bid,
+            *branch.root(),
+            score.value(),
+            bid.generate_prover_id(
+                secret_k,
+                seed,
+                BlsScalar::from(u64::MAX),
+                BlsScalar::from(STEP),
+            ),
+        );
+        assert!(verify_blind_bid(&pub_params, &vk, LABEL, &proof, &pi).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn timestamp_encoding_preserves_ordering() {
+        assert_eq!(timestamp_to_scalar(0), BlsScalar::zero());
+        assert_eq!(
+            timestamp_to_scalar(u64::MAX) + BlsScalar::one(),
+            BlsScalar::from(2u64).pow(&[64, 0, 0, 0])
+        );
+    }
+}
+
+#[cfg(test)]
+mod byte_verification_tests {
+    use super::*;
+
+    const LABEL: &[u8] = b"BytesVerification";
+
+    #[test]
+    fn verify_proof_from_bytes() -> Result<()> {
+        let pub_params =
+            PublicParameters::setup(1 << 17, &mut rand::thread_rng())?;
+        let mut tree = BidTree::<MemStore>::new();
+        let secret = JubJubScalar::random(&mut rand::thread_rng());
+        let secret_k = BlsScalar::random(&mut rand::thread_rng());
+        tree.push(random_bid(&secret, secret_k).into())?;
+        let secret: JubJubAffine = (GENERATOR_EXTENDED * &secret).into();
+        let seed = BlsScalar::random(&mut rand::thread_rng());
+
+        let (_, proof, pi, vk) =
+            prove(&pub_params, &tree, 0, secret, secret_k, seed, ROUND, LABEL)?;
+        let pub_params_bytes = pub_params.to_bytes();
+        let vk_bytes = vk.to_bytes();
+        let proof_bytes = proof.to_bytes();
+
+        assert_eq!(
+            verify_blind_bid_bytes(
+                &pub_params_bytes[..],
+                &vk_bytes[..],
+                LABEL,
+                &proof_bytes[..],
+                &pi.to_bytes()[..],
+            ),
+            VERIFY_OK
+        );
+
+        let mut tampered_pi = pi;
+        tampered_pi.score += BlsScalar::one();
+        assert_eq!(
+            verify_blind_bid_bytes(
+                &pub_params_bytes[..],
+                &vk_bytes[..],
+                LABEL,
+                &proof_bytes[..],
+                &tampered_pi.to_bytes()[..],
+            ),
+            VERIFY_ERR_INVALID_PROOF
+        );
+
+        assert_eq!(
+            verify_blind_bid_bytes(
+                &pub_params_bytes[..],
+                &vk_bytes[..],
+                LABEL,
+                &proof_bytes[..],
+                &pi.to_bytes()[1..],
+            ),
+            VERIFY_ERR_PUBLIC_INPUTS
+        );
+
+        assert_eq!(
+            verify_blind_bid_bytes(
+                &pub_params_bytes[..],
+                &[0u8; 8],
+                LABEL,
+                &proof_bytes[..],
+                &pi.to_bytes()[..],
+            ),
+            VERIFY_ERR_VERIFIER_KEY
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod bundle_tests {
+    use super::*;
+    use dusk_poseidon::tree::PoseidonBranch;
+
+    const LABEL: &[u8] = b"VerifierBundle";
+
+    #[test]
+    fn verify_with_bundle_only() -> Result<()> {
+        let pub_params =
+            PublicParameters::setup(1 << 17, &mut rand::thread_rng())?;
+        let mut tree = BidTree::<MemStore>::new();
+        let secret = JubJubScalar::random(&mut rand::thread_rng());
+        let secret_k = BlsScalar::random(&mut rand::thread_rng());
+        tree.push(random_bid(&secret, secret_k).into())?;
+        let secret: JubJubAffine = (GENERATOR_EXTENDED * &secret).into();
+        let seed = BlsScalar::random(&mut rand::thread_rng());
+
+        let (_, proof, pi, _) =
+            prove(&pub_params, &tree, 0, secret, secret_k, seed, ROUND, LABEL)?;
+
+        // The bundle is exported once from a placeholder circuit.
+        let branch = PoseidonBranch::<17>::default();
+        let mut circuit = BlindBidCircuit::for_keygen(&branch, 1 << 15);
+        let bundle_bytes = circuit
+            .export_verifier_bundle(&pub_params, LABEL)
+            .expect("Bundle export error")
+            .to_bytes();
+
+        // The client only holds the serialized bundle.
+        let bundle = BlindBidVerifierBundle::from_bytes(&bundle_bytes, LABEL)
+            .expect("Bundle deserialization error");
+        assert_eq!(bundle.label(), LABEL);
+        bundle
+            .verify(&proof, &pi)
+            .expect("Proof verification error");
+
+        let mut tampered_pi = pi;
+        tampered_pi.score += BlsScalar::one();
+        assert!(matches!(
+            bundle.verify(&proof, &tampered_pi),
+            Err(BlindBidError::ProofVerificationFailed)
+        ));
+
+        // A batch shares the verifier and the Public Inputs buffer.
+        bundle
+            .verify_batch(&[(&proof, pi), (&proof, pi)])
+            .expect("Batch verification error");
+        assert!(matches!(
+            bundle.verify_batch(&[(&proof, pi), (&proof, tampered_pi)]),
+            Err(BlindBidError::BatchVerificationFailed { index: 1 })
+        ));
+
+        assert!(matches!(
+            BlindBidVerifierBundle::from_bytes(
+                &bundle_bytes[..bundle_bytes.len() - 1],
+                LABEL
+            ),
+            Err(BlindBidError::MalformedBundle)
+        ));
+        assert!(matches!(
+            BlindBidVerifierBundle::from_bytes(&bundle_bytes, b"OtherLabel"),
+            Err(BlindBidError::TranscriptLabelMismatch)
+        ));
+
+        // The trim size follows the label and precedes the 7 positions of
+        // the Public Inputs.
+        let mut oversized = bundle_bytes.clone();
+        let trim_size = oversized.len() - 8 * 8;
+        oversized[trim_size..trim_size + 8]
+            .copy_from_slice(&u64::MAX.to_le_bytes());
+        assert!(matches!(
+            BlindBidVerifierBundle::from_bytes(&oversized, LABEL),
+            Err(BlindBidError::TrimSizeTooLarge { .. })
+        ));
+
+        // Contexts with a different label are rejected before verifying.
+        assert!(ProofContext::new(LABEL)
+            .verify_with_bundle(&bundle, &proof, &pi)
+            .is_ok());
+        assert!(matches!(
+            ProofContext::default().verify_with_bundle(&bundle, &proof, &pi),
+            Err(BlindBidError::TranscriptLabelMismatch)
+        ));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod one_call_tests {
+    use super::*;
+    use crate::{
+        prove_blind_bid, BlindBidKeys, BlindBidProver, BlindBidVerifier,
+    };
+    use dusk_poseidon::tree::PoseidonBranch;
+
+    const LABEL: &[u8] = b"OneCall";
+
+    #[test]
+    fn prove_in_one_call() -> Result<()> {
+        let pub_params =
+            PublicParameters::setup(1 << 17, &mut rand::thread_rng())?;
+        let mut tree = BidTree::<MemStore>::new();
+        let secret = JubJubScalar::random(&mut rand::thread_rng());
+        let secret_k = BlsScalar::random(&mut rand::thread_rng());
+        let bid = random_bid(&secret, secret_k);
+        tree.push(bid.into())?;
+        let secret: JubJubAffine = (GENERATOR_EXTENDED * &secret).into();
+        let seed = BlsScalar::random(&mut rand::thread_rng());
+        let branch = tree
+            .poseidon_branch(0)?
+            .expect("Poseidon Branch Extraction");
+
+        // The keys are compiled once from a placeholder circuit.
+        let placeholder_branch = PoseidonBranch::<17>::default();
+        let mut circuit =
+            BlindBidCircuit::for_keygen(&placeholder_branch, 1 << 15);
+        let (pk, vk) = circuit.compile(&pub_params)?;
+
+        let (proof, pi) = prove_blind_bid(
+            &pub_params,
+            &pk,
+            &bid,
+            &secret,
+            secret_k,
+            &branch,
+            seed,
+            ROUND,
+            STEP,
+            LABEL,
+        )?;
+        assert_eq!(pi.root, *branch.root());
+        assert_eq!(pi.bid_hash, bid.hash());
+        verify_blind_bid(&pub_params, &vk, LABEL, &proof, &pi)?;
+
+        // Expired Bids are rejected before proving.
+        let expired = Bid::from_parts(
+            bid.encrypted_data(),
+            bid.nonce(),
+            *bid.stealth_address(),
+            bid.hashed_secret(),
+            bid.commitment(),
+            ROUND - 1,
+            ROUND - 1,
+            0,
+        );
+        assert!(matches!(
+            prove_blind_bid(
+                &pub_params,
+                &pk,
+                &expired,
+                &secret,
+                secret_k,
+                &branch,
+                seed,
+                ROUND,
+                STEP,
+                LABEL,
+            ),
+            Err(BlindBidError::ExpiredBid)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn service_prover_and_verifier() -> Result<()> {
+        let pub_params =
+            PublicParameters::setup(1 << 17, &mut rand::thread_rng())?;
+        let keys = BlindBidKeys::compile(&pub_params, 1 << 15)?;
+        let prover =
+            BlindBidProver::new(&pub_params, keys.prover_key().clone(), LABEL);
+        let verifier = BlindBidVerifier::new(
+            &pub_params,
+            keys.verifier_key().clone(),
+            LABEL,
+        );
+
+        let mut tree = BidTree::<MemStore>::new();
+        let secret = JubJubScalar::random(&mut rand::thread_rng());
+        let secret_k = BlsScalar::random(&mut rand::thread_rng());
+        let (_, branch) = tree.push_bid(random_bid(&secret, secret_k))?;
+        let bid = tree.get(0)?.expect("Bid not found").bid();
+        let secret: JubJubAffine = (GENERATOR_EXTENDED * &secret).into();
+        let seed = BlsScalar::random(&mut rand::thread_rng());
+        let score = Score::compute(
+            &bid,
+            &secret,
+            secret_k,
+            *branch.root(),
+            seed,
+            ROUND,
+            STEP,
+        )?;
+        let prover_id = bid.generate_prover_id(
+            secret_k,
+            seed,
+            BlsScalar::from(ROUND),
+            BlsScalar::from(STEP),
+        );
+
+        let proof = prover.prove(
+            &bid, score, &secret, secret_k, &branch, seed, ROUND, STEP,
+        )?;
+        let pi = BlindBidPublicInputs::from_bid(
+            &bid,
+            *branch.root(),
+            score.value(),
+            prover_id,
+        );
+        verifier.verify(&proof, &pi)?;
+
+        let mut tampered_pi = pi;
+        tampered_pi.prover_id += BlsScalar::one();
+        assert!(matches!(
+            verifier.verify(&proof, &tampered_pi),
+            Err(BlindBidError::ProofVerificationFailed)
+        ));
+        assert!(matches!(
+            prover.prove(
+                &bid,
+                score,
+                &secret,
+                secret_k,
+                &branch,
+                seed,
+                u64::MAX,
+                STEP,
+            ),
+            Err(BlindBidError::ExpiredBid)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn keygen_circuit_matches_full_circuit() -> Result<()> {
+        let pub_params =
+            PublicParameters::setup(1 << 17, &mut rand::thread_rng())?;
+        let mut tree = BidTree::<MemStore>::new();
+        let secret = JubJubScalar::random(&mut rand::thread_rng());
+        let secret_k = BlsScalar::random(&mut rand::thread_rng());
+        tree.push(random_bid(&secret, secret_k).into())?;
+        let secret: JubJubAffine = (GENERATOR_EXTENDED * &secret).into();
+        let seed = BlsScalar::random(&mut rand::thread_rng());
+        let (_, _, _, vk) =
+            prove(&pub_params, &tree, 0, secret, secret_k, seed, ROUND, LABEL)?;
+
+        let branch = PoseidonBranch::<17>::default();
+        let (_, keygen_vk) = BlindBidCircuit::for_keygen(&branch, 1 << 15)
+            .compile(&pub_params)?;
+        assert_eq!(keygen_vk.to_bytes()[..], vk.to_bytes()[..]);
+        Ok(())
+    }
+
+    #[test]
+    fn constraint_count_fits_the_trim_size() -> Result<(), BlindBidError> {
+        let branch = PoseidonBranch::<17>::default();
+        let circuit = BlindBidCircuit::for_keygen(&branch, 1 << 15);
+        let count = circuit.constraint_count()?;
+        assert!(count > 0);
+        assert!(count < 1 << 15);
+        assert!(
+            circuit.with_private_commitment().constraint_count()? < 1 << 15
+        );
+        assert!(BlindBidCircuit::<'_, 17>::recommended_trim_size()? >= count);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod depth_tests {
+    use super::*;
+    use crate::proof::tree_assets::BidLeaf;
+    use dusk_poseidon::tree::{
+        PoseidonBranch, PoseidonMaxAnnotation, PoseidonTree,
+    };
+
+    const LABEL: &[u8] = b"ShallowTree";
+    const DEPTH: usize = 4;
+
+    fn placeholder_circuit<const D: usize>(
+        branch: &PoseidonBranch<D>,
+    ) -> BlindBidCircuit<'_, D> {
+        BlindBidCircuit {
+            bid: BidWitness::default(),
+            score: Score::default(),
+            secret_k: BlsScalar::one(),
+            seed: BlsScalar::zero(),
+            latest_consensus_round: BlsScalar::zero(),
+            latest_consensus_step: BlsScalar::zero(),
+            branch: branch.into(),
+            trim_size: 1 << 15,
+            private_commitment: false,
+            pi_positions: vec![],
+        }
+    }
+
+    #[test]
+    fn shallow_tree_proof() -> Result<()> {
+        let pub_params =
+            PublicParameters::setup(1 << 17, &mut rand::thread_rng())?;
+        let mut tree: PoseidonTree<
+            BidLeaf,
+            PoseidonMaxAnnotation,
+            MemStore,
+            DEPTH,
+        > = PoseidonTree::new();
+        let secret = JubJubScalar::random(&mut rand::thread_rng());
+        let secret_k = BlsScalar::random(&mut rand::thread_rng());
+        let bid = random_bid(&secret, secret_k);
+        tree.push(bid.into()).expect("Bid insertion error");
+        let secret: JubJubAffine = (GENERATOR_EXTENDED * &secret).into();
+        let seed = BlsScalar::random(&mut rand::thread_rng());
+        let branch = tree
+            .branch(0)
+            .expect("Poseidon Branch Extraction")
+            .expect("Bid not found");
+
+        // The Merkle Opening of a shallow tree takes fewer constraints.
+        let shallow_branch = PoseidonBranch::<DEPTH>::default();
+        let mut composer = StandardComposer::new();
+        placeholder_circuit(&shallow_branch).gadget(&mut composer)?;
+        let shallow_size = composer.circuit_size();
+        let default_branch = PoseidonBranch::<17>::default();
+        let mut composer = StandardComposer::new();
+        placeholder_circuit(&default_branch).gadget(&mut composer)?;
+        assert!(shallow_size < composer.circuit_size());
+
+        let (pk, vk) =
+            placeholder_circuit(&shallow_branch).compile(&pub_params)?;
+        let (proof, pi) = crate::prove_blind_bid(
+            &pub_params,
+            &pk,
+            &bid,
+            &secret,
+            secret_k,
+            &branch,
+            seed,
+            ROUND,
+            STEP,
+            LABEL,
+        )?;
+        placeholder_circuit(&shallow_branch).verify_proof(
+            &pub_params,
+            &vk,
+            LABEL,
+            &proof,
+            &pi.to_public_inputs(),
+        )?;
+
+        // A circuit for a tree of a different depth does not verify it.
+        assert!(placeholder_circuit(&default_branch)
+            .verify_proof(
+                &pub_params,
+                &vk,
+                LABEL,
+                &proof,
+                &pi.to_public_inputs()
+            )
+            .is_err());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod sparse_tests {
+    use super::*;
+    use crate::{prove_blind_bid, SparseBidTree, SparseBranch};
+    use dusk_poseidon::tree::PoseidonBranch;
+
+    const LABEL: &[u8] = b"SparseTree";
+    const DEPTH: usize = 4;
+
+    #[test]
+    fn sparse_tree_proof() -> Result<()> {
+        let pub_params =
+            PublicParameters::setup(1 << 17, &mut rand::thread_rng())?;
+        let secret = JubJubScalar::random(&mut rand::thread_rng());
+        let secret_k = BlsScalar::random(&mut rand::thread_rng());
+        let key_of = |bid: &Bid| SparseBidTree::<DEPTH>::key(&bid.hash());
+        let bid = random_bid(&secret, secret_k);
+        let key = key_of(&bid);
+        // Stored under another parent, so its path differs from the leaves.
+        let other_bid =
+            core::iter::repeat_with(|| random_bid(&secret, secret_k))
+                .find(|other| key_of(other) >> 2 != key >> 2)
+                .expect("Bid creation error");
+        let colliding_bid =
+            core::iter::repeat_with(|| random_bid(&secret, secret_k))
+                .find(|other| key_of(other) == key)
+                .expect("Bid creation error");
+        let secret: JubJubAffine = (GENERATOR_EXTENDED * &secret).into();
+        let seed = BlsScalar::random(&mut rand::thread_rng());
+
+        // Bids are stored at the key derived from their hash.
+        let mut tree = SparseBidTree::<DEPTH>::new();
+        assert_eq!(tree.insert(&bid)?, key);
+        assert_eq!(tree.insert(&bid)?, key);
+        tree.insert(&other_bid)?;
+        let root = tree.root();
+        assert!(matches!(
+            tree.insert(&colliding_bid),
+            Err(BlindBidError::SparseKeyTaken { key: taken }) if taken == key
+        ));
+        assert_eq!(tree.root(), root);
+        assert!(tree.branch(&colliding_bid.hash()).is_none());
+
+        let branch = tree.branch(&bid.hash()).expect("Bid not found");
+        let other_branch =
+            tree.branch(&other_bid.hash()).expect("Bid not found");
+        assert_eq!(branch.root(), &root);
+        assert!(branch.verify(&bid.hash()));
+        assert!(!branch.verify(&other_bid.hash()));
+        assert!(!other_branch.verify(&bid.hash()));
+
+        // The keys of sparse openings are compiled from a placeholder branch.
+        let placeholder = SparseBranch::<DEPTH>::default();
+        let (pk, vk) = BlindBidCircuit::for_keygen(&placeholder, 1 << 15)
+            .compile(&pub_params)?;
+        let (proof, pi) = prove_blind_bid(
+            &pub_params,
+            &pk,
+            &bid,
+            &secret,
+            secret_k,
+            &branch,
+            seed,
+            ROUND,
+            STEP,
+            LABEL,
+        )?;
+        assert_eq!(pi.root, root);
+        BlindBidCircuit::for_keygen(&placeholder, 1 << 15).verify_proof(
+            &pub_params,
+            &vk,
+            LABEL,
+            &proof,
+            &pi.to_public_inputs(),
+        )?;
+
+        // The path of the opening is bound to the key of the Bid, so the
+        // branch of another key doesn't prove it.
+        let (wrong_proof, wrong_pi) = prove_blind_bid(
+            &pub_params,
+            &pk,
+            &bid,
+            &secret,
+            secret_k,
+            &other_branch,
+            seed,
+            ROUND,
+            STEP,
+            LABEL,
+        )?;
+        assert!(BlindBidCircuit::for_keygen(&placeholder, 1 << 15)
+            .verify_proof(
+                &pub_params,
+                &vk,
+                LABEL,
+                &wrong_proof,
+                &wrong_pi.to_public_inputs()
+            )
+            .is_err());
+
+        // A circuit with a dense opening of the same depth doesn't verify
+        // proofs of sparse ones.
+        let dense = PoseidonBranch::<DEPTH>::default();
+        assert!(BlindBidCircuit::for_keygen(&dense, 1 << 15)
+            .verify_proof(
+                &pub_params,
+                &vk,
+                LABEL,
+                &proof,
+                &pi.to_public_inputs()
+            )
+            .is_err());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod batch_tests {
+    use super::*;
+    use crate::verify_blind_bids_batch;
+
+    const LABEL: &[u8] = b"BatchProving";
+
+    #[test]
+    fn batch_proofs_match_single_proofs() -> Result<()> {
+        let pub_params =
+            PublicParameters::setup(1 << 17, &mut rand::thread_rng())?;
+        let mut tree = BidTree::<MemStore>::new();
+        let seed = BlsScalar::random(&mut rand::thread_rng());
+
+        let owners: Vec<(JubJubScalar, BlsScalar)> = (0..3)
+            .map(|_| {
+                (
+                    JubJubScalar::random(&mut rand::thread_rng()),
+                    BlsScalar::random(&mut rand::thread_rng()),
+                )
+            })
+            .collect();
+        for (secret, secret_k) in owners.iter() {
+            tree.push(random_bid(secret, *secret_k).into())?;
+        }
+
+        let bids = (0..owners.len() as u64)
+            .map(|idx| {
+                tree.get(idx).map(|leaf| leaf.expect("Bid not found").bid())
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let branches = (0..owners.len() as u64)
+            .map(|idx| {
+                tree.poseidon_branch(idx)
+                    .map(|branch| branch.expect("Poseidon Branch Extraction"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut pis = vec![];
+        let mut circuits: Vec<BlindBidCircuit<'_, 17>> = owners
+            .iter()
+            .zip(branches.iter())
+            .enumerate()
+            .map(|(idx, ((secret, secret_k), branch))| {
+                let bid = bids[idx];
+                let secret: JubJubAffine = (GENERATOR_EXTENDED * secret).into();
+                let score = Score::compute(
+                    &bid,
+                    &secret,
+                    *secret_k,
+                    *branch.root(),
+                    seed,
+                    ROUND,
+                    STEP,
+                )
+                .expect("Score computation error");
+                let prover_id = bid.generate_prover_id(
+                    *secret_k,
+                    seed,
+                    BlsScalar::from(ROUND),
+                    BlsScalar::from(STEP),
+                );
+                pis.push(BlindBidPublicInputs::from_bid(
+                    &bid,
+                    *branch.root(),
+                    score.value(),
+                    prover_id,
+                ));
+
+                BlindBidCircuit {
+                    bid: BidWitness::new(&bid, &secret),
+                    score,
+                    secret_k: *secret_k,
+                    seed,
+                    latest_consensus_round: BlsScalar::from(ROUND),
+                    latest_consensus_step: BlsScalar::from(STEP),
+                    branch: branch.into(),
+                    trim_size: 1 << 15,
+                    private_commitment: false,
+                    pi_positions: vec![],
+                }
+            })
+            .collect();
+
+        let (pk, vk) = circuits[0].clone().compile(&pub_params)?;
+
+        // Naive path: one full `gen_proof` call per Bid.
+        let single_proofs = circuits
+            .clone()
+            .iter_mut()
+            .map(|circuit| circuit.gen_proof(&pub_params, &pk, LABEL))
+            .collect::<Result<Vec<Proof>>>()?;
+        // Batched path: a single trimming and Prover for all of the Bids.
+        let batch_proofs = BlindBidCircuit::gen_proofs_batch(
+            &pub_params,
+            &pk,
+            &mut circuits,
+            LABEL,
+        )?;
+
+        assert_eq!(batch_proofs.len(), single_proofs.len());
+        for ((batch, single), pi) in batch_proofs
+            .iter()
+            .zip(single_proofs.iter())
+            .zip(pis.iter())
+        {
+            verify_blind_bid(&pub_params, &vk, LABEL, batch, pi)
+                .expect("Batch proof verification error");
+            verify_blind_bid(&pub_params, &vk, LABEL, single, pi)
+                .expect("Single proof verification error");
+        }
+
+        // Proofs must not be interchangeable between Bids of the batch.
+        assert!(verify_blind_bid(
+            &pub_params,
+            &vk,
+            LABEL,
+            &batch_proofs[0],
+            &pis[1]
+        )
+        .is_err());
+
+        // The shared-setup verification accepts what the single one does.
+        let mut items: Vec<(Proof, BlindBidPublicInputs)> = batch_proofs
+            .iter()
+            .cloned()
+            .zip(pis.iter().cloned())
+            .collect();
+        verify_blind_bids_batch(&pub_params, &vk, LABEL, &items)?;
+
+        items[1].1.score += BlsScalar::one();
+        assert!(matches!(
+            verify_blind_bids_batch(&pub_params, &vk, LABEL, &items),
+            Err(BlindBidError::BatchVerificationFailed { index: 1 })
+        ));
+        let no_items: &[(Proof, BlindBidPublicInputs)] = &[];
+        assert!(
+            verify_blind_bids_batch(&pub_params, &vk, LABEL, no_items).is_ok()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn one_proof_against_several_public_inputs() -> Result<()> {
+        let pub_params =
+            PublicParameters::setup(1 << 17, &mut rand::thread_rng())?;
+        let mut tree = BidTree::<MemStore>::new();
+        let secret = JubJubScalar::random(&mut rand::thread_rng());
+        let secret_k = BlsScalar::random(&mut rand::thread_rng());
+        tree.push(random_bid(&secret, secret_k).into())?;
+        let secret: JubJubAffine = (GENERATOR_EXTENDED * &secret).into();
+        let seed = BlsScalar::random(&mut rand::thread_rng());
+        let (_, proof, pi, vk) =
+            prove(&pub_params, &tree, 0, secret, secret_k, seed, ROUND, LABEL)?;
+
+        // The same proof is checked against a stale root without cloning it.
+        let mut stale_root = pi;
+        stale_root.root += BlsScalar::one();
+        let proof = &proof;
+        verify_blind_bid(&pub_params, &vk, LABEL, proof, &pi)?;
+        assert!(
+            verify_blind_bid(&pub_params, &vk, LABEL, proof, &stale_root)
+                .is_err()
+        );
+        assert!(matches!(
+            verify_blind_bids_batch(
+                &pub_params,
+                &vk,
+                LABEL,
+                &[(proof, pi), (proof, stale_root)]
+            ),
+            Err(BlindBidError::BatchVerificationFailed { index: 1 })
+        ));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tree_tests {
+    use super::*;
+    use dusk_poseidon::tree::PoseidonLeaf;
+
+    #[test]
+    fn circuit_from_inputs() -> Result<()> {
+        let mut tree = BidTree::<MemStore>::new();
+        let secret = JubJubScalar::random(&mut rand::thread_rng());
+        let secret_k = BlsScalar::one();
+        let (_, branch) = tree.push_bid(random_bid(&secret, secret_k))?;
+        let secret: JubJubAffine = (GENERATOR_EXTENDED * secret).into();
+        let bid = tree.get(0)?.expect("Bid not found").bid();
+
+        let circuit = BlindBidCircuit::from_inputs(
+            &bid,
+            Score::default(),
+            &secret,
+            secret_k,
+            &branch,
+            BlsScalar::one(),
+            ROUND,
+            STEP,
+        );
+        assert_eq!(circuit.trim_size, 1 << 15);
+        assert!(circuit.pi_positions.is_empty());
+        assert_eq!(circuit.bid.hash(), bid.hash());
+        assert_eq!(circuit.latest_consensus_round, BlsScalar::from(ROUND));
+        assert_eq!(circuit.with_trim_size(1 << 16).trim_size, 1 << 16);
+        Ok(())
+    }
+
+    #[test]
+    fn bulk_insert_is_atomic() -> Result<()> {
+        let mut tree = BidTree::<MemStore>::with_capacity(3);
+        let bid = random_bid(&JubJubScalar::one(), BlsScalar::one());
+        tree.extend(&[bid.into(), bid.into()])?;
+        let root = tree.root()?;
+
+        assert!(matches!(
+            tree.extend(&[bid.into(), bid.into()]),
+            Err(BlindBidError::TreeFull {
+                capacity: 3,
+                required: 4
+            })
+        ));
+        assert_eq!(tree.len(), 2);
+        assert_eq!(tree.root()?, root);
+
+        tree.extend(&[bid.into()])?;
+        assert_eq!(tree.len(), 3);
+        assert_eq!(tree.get(2)?.expect("Bid not found").bid().pos(), 2);
+        assert!(matches!(
+            tree.push(bid.into()),
+            Err(BlindBidError::TreeFull {
+                capacity: 3,
+                required: 4
+            })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn bid_leaf_roundtrip() {
+        let bid =
+            random_bid(&JubJubScalar::one(), BlsScalar::one()).with_position(7);
+        let leaf = crate::BidLeaf::from(bid);
+        assert_eq!(PoseidonLeaf::<MemStore>::poseidon_hash(&leaf), bid.hash());
+        assert_eq!(PoseidonLeaf::<MemStore>::pos(&leaf), 7);
+        assert_eq!(Bid::from(leaf), bid);
+    }
+
+    #[test]
+    fn pushed_bids_get_their_tree_position() -> Result<()> {
+        let mut tree = BidTree::<MemStore>::new();
+        let bid = random_bid(&JubJubScalar::one(), BlsScalar::one())
+            .with_position(42);
+
+        for expected in 0..3u64 {
+            let (pos, branch) = tree.push_bid(bid)?;
+            assert_eq!(pos, expected);
+            assert_eq!(tree.get(pos)?.expect("Bid not found").bid().pos(), pos);
+            assert_eq!(*branch.root(), tree.root()?);
+        }
+        assert_eq!(tree.len(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn bid_branch_verification() -> Result<()> {
+        let mut tree = BidTree::<MemStore>::new();
+        let mut other_tree = BidTree::<MemStore>::new();
+        let secret = JubJubScalar::one();
+        for _ in 0..3 {
+            tree.push_bid(random_bid(&secret, BlsScalar::one()))?;
+            other_tree.push_bid(random_bid(&secret, BlsScalar::one()))?;
+        }
+        let (pos, branch) =
+            tree.push_bid(random_bid(&secret, BlsScalar::one()))?;
+        let (_, other_branch) =
+            other_tree.push_bid(random_bid(&secret, BlsScalar::one()))?;
+        let bid = tree.get(pos)?.expect("Bid not found").bid();
+        let root = tree.root()?;
+        let other_root = other_tree.root()?;
+
+        assert!(bid.verify_branch(&branch, &root));
+        assert!(!bid.verify_branch(&branch, &other_root));
+        assert!(!bid.verify_branch(&other_branch, &root));
+        assert!(!bid.verify_branch(&other_branch, &other_root));
+        assert!(!bid.with_position(pos + 1).verify_branch(&branch, &root));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod candidate_set_tests {
+    use super::*;
+
+    const LABEL: &[u8] = b"CandidateSet";
+
+    fn rejection(
+        res: core::result::Result<(), BlindBidError>,
+    ) -> (usize, CandidateRejection) {
+        match res {
+            Err(BlindBidError::InvalidCandidate { index, reason }) => {
+                (index, reason)
+            }
+            _ => panic!("Expected an InvalidCandidate error"),
+        }
+    }
+
+    #[test]
+    fn candidate_set_validation() -> Result<()> {
+        let pub_params =
+            PublicParameters::setup(1 << 17, &mut rand::thread_rng())?;
+        let mut tree = BidTree::<MemStore>::new();
+        let seed = BlsScalar::random(&mut rand::thread_rng());
+
+        // Two Bids owned by different identities and a third one which
+        // shares the `secret_k` of the first one.
+        let secret_a = JubJubScalar::random(&mut rand::thread_rng());
+        let secret_k_a = BlsScalar::random(&mut rand::thread_rng());
+        let secret_b = JubJubScalar::random(&mut rand::thread_rng());
+        let secret_k_b = BlsScalar::random(&mut rand::thread_rng());
+        tree.push(random_bid(&secret_a, secret_k_a).into())?;
+        tree.push(random_bid(&secret_b, secret_k_b).into())?;
+        tree.push(random_bid(&secret_a, secret_k_a).into())?;
+        let secret_a: JubJubAffine = (GENERATOR_EXTENDED * &secret_a).into();
+        let secret_b: JubJubAffine = (GENERATOR_EXTENDED * &secret_b).into();
+
+        let (bid_a, proof_a, pi_a, vk) = prove(
+            &pub_params,
+            &tree,
+            0,
+            secret_a,
+            secret_k_a,
+            seed,
+            ROUND,
+            LABEL,
+        )?;
+        let (bid_b, proof_b, pi_b, _) = prove(
+            &pub_params,
+            &tree,
+            1,
+            secret_b,
+            secret_k_b,
+            seed,
+            ROUND,
+            LABEL,
+        )?;
+        let (bid_c, proof_c, pi_c, _) = prove(
+            &pub_params,
+            &tree,
+            2,
+            secret_a,
+            secret_k_a,
+            seed,
+            ROUND,
+            LABEL,
+        )?;
+
+        // A set of correct and unrelated entries is valid.
+        let entries = [
+            (bid_a, proof_a.clone(), pi_a),
+            (bid_b, proof_b.clone(), pi_b),
+        ];
+        assert!(validate_candidate_set(
+            &pub_params,
+            &vk,
+            LABEL,
+            &entries,
+            ROUND
+        )
+        .is_ok());
+
+        // The same Bid can't be included twice.
+        let entries = [
+            (bid_a, proof_a.clone(), pi_a),
+            (bid_a, proof_a.clone(), pi_a),
+        ];
+        assert_eq!(
+            rejection(validate_candidate_set(
+                &pub_params,
+                &vk,
+                LABEL,
+                &entries,
+                ROUND
+            )),
+            (1, CandidateRejection::DuplicateBid)
+        );
+
+        // The same identity can't participate twice in the same round.
+        let entries = [(bid_a, proof_a.clone(), pi_a), (bid_c, proof_c, pi_c)];
+        assert_eq!(
+            rejection(validate_candidate_set(
+                &pub_params,
+                &vk,
+                LABEL,
+                &entries,
+                ROUND
+            )),
+            (1, CandidateRejection::DuplicateProverId)
+        );
+
+        // Public Inputs must belong to the Bid they're paired with.
+        let entries = [(bid_b, proof_a.clone(), pi_a)];
+        assert_eq!(
+            rejection(validate_candidate_set(
+                &pub_params,
+                &vk,
+                LABEL,
+                &entries,
+                ROUND
+            )),
+            (0, CandidateRejection::PublicInputsMismatch)
+        );
+
+        // Proofs must verify against the Public Inputs.
+        let entries = [(bid_a, proof_a, pi_a), (bid_b, proof_a, pi_b)];
+        assert_eq!(
+            rejection(validate_candidate_set(
+                &pub_params,
+                &vk,
+                LABEL,
+                &entries,
+                ROUND
+            )),
+            (1, CandidateRejection::InvalidProof)
+        );
+
+        // Bids must be eligible at the validated round.
+        let mut non_eligible_bid = bid_b;
+        non_eligible_bid.set_eligibility(ROUND);
+        let entries = [(non_eligible_bid, proof_b.clone(), pi_b)];
+        assert_eq!(
+            rejection(validate_candidate_set(
+                &pub_params,
+                &vk,
+                LABEL,
+                &entries,
+                ROUND
+            )),
+            (0, CandidateRejection::NonEligibleBid)
+        );
+
+        // Bids must not be expired at the validated round.
+        let entries = [(bid_b, proof_b, pi_b)];
+        assert_eq!(
+            rejection(validate_candidate_set(
+                &pub_params,
+                &vk,
+                LABEL,
+                &entries,
+                u64::MAX
+            )),
+            (0, CandidateRejection::ExpiredBid)
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,264 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Circuit used to prove that the value committed inside of a
+//! [Bid](crate::Bid) lies inside of a public `[min, max]` range without
+//! revealing it.
+
+use crate::bid::{blinder_generator, value_generator, Bid, BidTier};
+use anyhow::Result;
+use dusk_bls12_381::BlsScalar;
+use dusk_jubjub::JubJubAffine;
+use dusk_plonk::constraint_system::ecc::scalar_mul::fixed_base::scalar_mul;
+use dusk_plonk::prelude::*;
+use plonk_gadgets::AllocatedScalar;
+
+/// The ValueBoundsCircuit is used to prove or verify that the value hidden
+/// in a Bid commitment lies inside of the public range `[min, max]`.
+///
+/// It's the in-circuit counterpart of
+/// [`Bid::new_with_bounds`](crate::Bid::new_with_bounds), for deployments
+/// that don't use the `[V_RAW_MIN, V_RAW_MAX]` range.
+///
+/// The [Public Inputs](dusk_plonk::circuit_builder::PublicInput) **in order**
+/// used for the proof are:
+/// 1. Bid commitment field.
+/// 2. Minimum `min`.
+/// 3. Maximum `max`.
+#[cfg_attr(docsrs, doc(cfg(all(feature = "proof", feature = "canon"))))]
+#[derive(Debug, Clone)]
+pub struct ValueBoundsCircuit {
+    /// Value committed inside of the Bid.
+    pub value: JubJubScalar,
+    /// Blinder used to generate the Bid commitment.
+    pub blinder: JubJubScalar,
+    /// Commitment of the Bid.
+    pub commitment: JubJubAffine,
+    /// Public minimum that the value has to reach.
+    pub min: u64,
+    /// Public maximum that the value can't exceed.
+    pub max: u64,
+    /// Trim size of the Public Parameters used by the PLONK mechanism.
+    pub trim_size: usize,
+    /// Positions of the Public Inputs used with the proof.
+    pub pi_positions: Vec<PublicInput>,
+}
+
+impl ValueBoundsCircuit {
+    /// Fills the circuit to prove that the value of `bid`, decrypted with
+    /// `secret`, lies inside of the bounds of `tier`.
+    ///
+    /// If the decryption fails, the value & blinder are set to zero, so the
+    /// proof fails to verify.
+    pub fn for_tier(
+        bid: &Bid,
+        secret: &JubJubAffine,
+        tier: BidTier,
+        trim_size: usize,
+    ) -> Self {
+        let (value, blinder) = bid
+            .decrypt_data(secret)
+            .map(|decrypted| (decrypted.value(), decrypted.blinder()))
+            .unwrap_or((JubJubScalar::zero(), JubJubScalar::zero()));
+        let (min, max) = tier.bounds();
+
+        ValueBoundsCircuit {
+            value,
+            blinder,
+            commitment: bid.commitment(),
+            min,
+            max,
+            trim_size,
+            pi_positions: vec![],
+        }
+    }
+}
+
+impl<'a> Circuit<'a> for ValueBoundsCircuit {
+    fn gadget(&mut self, composer: &mut StandardComposer) -> Result<()> {
+        let commitment = self.commitment;
+        let min = BlsScalar::from(self.min);
+        let max = BlsScalar::from(self.max);
+        let value = AllocatedScalar::allocate(composer, self.value.into());
+        let blinder = AllocatedScalar::allocate(composer, self.blinder.into());
+        let pi = self.get_mut_pi_positions();
+
+        // 1. c = C(v, b) Pedersen Commitment check
+        let p1 = scalar_mul(composer, value.var, value_generator());
+        let p2 = scalar_mul(composer, blinder.var, blinder_generator());
+        let computed_c = p1.point().fast_add(composer, *p2.point());
+        // Add PI constraint for the commitment computation check.
+        pi.push(PublicInput::AffinePoint(
+            commitment,
+            composer.circuit_size(),
+            composer.circuit_size() + 1,
+        ));
+        composer.assert_equal_public_point(computed_c, commitment);
+
+        // 2. v < 2^64
+        composer.range_gate(value.var, 64usize);
+
+        // Constraint the bounds to the public ones.
+        let min_var = composer.add_input(min);
+        pi.push(PublicInput::BlsScalar(-min, composer.circuit_size()));
+        composer.constrain_to_constant(min_var, BlsScalar::zero(), -min);
+        let max_var = composer.add_input(max);
+        pi.push(PublicInput::BlsScalar(-max, composer.circuit_size()));
+        composer.constrain_to_constant(max_var, BlsScalar::zero(), -max);
+
+        // 3. v >= min
+        // If v < min, the subtraction wraps around the field and the result
+        // can't fit in 64 bits.
+        let v_min_min = composer.add(
+            (BlsScalar::one(), value.var),
+            (-BlsScalar::one(), min_var),
+            BlsScalar::zero(),
+            BlsScalar::zero(),
+        );
+        composer.range_gate(v_min_min, 64usize);
+
+        // 4. v <= max
+        let max_min_v = composer.add(
+            (BlsScalar::one(), max_var),
+            (-BlsScalar::one(), value.var),
+            BlsScalar::zero(),
+            BlsScalar::zero(),
+        );
+        composer.range_gate(max_min_v, 64usize);
+        Ok(())
+    }
+
+    fn get_pi_positions(&self) -> &Vec<PublicInput> {
+        &self.pi_positions
+    }
+
+    fn get_mut_pi_positions(&mut self) -> &mut Vec<PublicInput> {
+        &mut self.pi_positions
+    }
+
+    fn get_trim_size(&self) -> usize {
+        self.trim_size
+    }
+
+    fn set_trim_size(&mut self, size: usize) {
+        self.trim_size = size;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dusk_jubjub::GENERATOR_EXTENDED;
+    use dusk_pki::{PublicSpendKey, SecretSpendKey};
+
+    const LABEL: &[u8] = b"ValueBounds";
+    const MIN: u64 = 1_000;
+    const MAX: u64 = 2_000;
+
+    fn prove_and_verify(value: u64, min: u64, max: u64) -> Result<()> {
+        let pub_params =
+            PublicParameters::setup(1 << 14, &mut rand::thread_rng())?;
+        let mut rng = rand::thread_rng();
+        let pk_r = PublicSpendKey::from(SecretSpendKey::random(&mut rng));
+        let secret = JubJubScalar::random(&mut rng);
+        let stealth_addr = pk_r.gen_stealth_address(&secret);
+        let secret: JubJubAffine = (GENERATOR_EXTENDED * secret).into();
+        let bid = Bid::new_with_bounds(
+            &mut rng,
+            &stealth_addr,
+            &JubJubScalar::from(value),
+            &secret,
+            BlsScalar::one(),
+            u64::MAX,
+            u64::MAX,
+            JubJubScalar::zero(),
+            JubJubScalar::from(u64::MAX),
+        )
+        .expect("Bid creation error");
+        let decrypted = bid.decrypt_data(&secret).expect("Decryption error");
+
+        let mut circuit = ValueBoundsCircuit {
+            value: decrypted.value(),
+            blinder: decrypted.blinder(),
+            commitment: bid.commitment(),
+            min,
+            max,
+            trim_size: 1 << 13,
+            pi_positions: vec![],
+        };
+        let (pk, vk) = circuit.compile(&pub_params)?;
+        let proof = circuit.gen_proof(&pub_params, &pk, LABEL)?;
+
+        let pi = vec![
+            PublicInput::AffinePoint(bid.commitment(), 0, 0),
+            PublicInput::BlsScalar(BlsScalar::from(min), 0),
+            PublicInput::BlsScalar(BlsScalar::from(max), 0),
+        ];
+        let mut circuit = ValueBoundsCircuit {
+            value: JubJubScalar::zero(),
+            blinder: JubJubScalar::zero(),
+            commitment: bid.commitment(),
+            min,
+            max,
+            trim_size: 1 << 13,
+            pi_positions: vec![],
+        };
+        circuit.verify_proof(&pub_params, &vk, LABEL, &proof, &pi)
+    }
+
+    #[test]
+    fn value_inside_bounds() -> Result<()> {
+        prove_and_verify(MIN, MIN, MAX)?;
+        prove_and_verify(MAX, MIN, MAX)
+    }
+
+    #[test]
+    fn value_outside_bounds() {
+        assert!(prove_and_verify(MIN - 1, MIN, MAX).is_err());
+        assert!(prove_and_verify(MAX + 1, MIN, MAX).is_err());
+    }
+
+    #[test]
+    fn tier_boundaries() -> Result<()> {
+        for tier in
+            [BidTier::Standard, BidTier::Range { min: MIN, max: MAX }].iter()
+        {
+            let (min, max) = tier.bounds();
+            prove_and_verify(min, min, max)?;
+            prove_and_verify(max, min, max)?;
+            assert!(prove_and_verify(min - 1, min, max).is_err());
+            assert!(prove_and_verify(max + 1, min, max).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn circuit_for_tier() {
+        let mut rng = rand::thread_rng();
+        let pk_r = PublicSpendKey::from(SecretSpendKey::random(&mut rng));
+        let stealth_addr = pk_r.gen_stealth_address(&JubJubScalar::one());
+        let secret: JubJubAffine =
+            (GENERATOR_EXTENDED * JubJubScalar::from(7u64)).into();
+        let tier = BidTier::Range { min: MIN, max: MAX };
+        let bid = Bid::new_tiered(
+            &mut rng,
+            &stealth_addr,
+            &JubJubScalar::from(MIN),
+            &secret,
+            BlsScalar::one(),
+            u64::MAX,
+            u64::MAX,
+            tier,
+        )
+        .expect("Bid creation error");
+
+        let circuit =
+            ValueBoundsCircuit::for_tier(&bid, &secret, tier, 1 << 13);
+        assert_eq!(circuit.value, JubJubScalar::from(MIN));
+        assert_eq!((circuit.min, circuit.max), tier.bounds());
+        assert_eq!(circuit.commitment, bid.commitment());
+    }
+}
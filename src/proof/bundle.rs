@@ -0,0 +1,300 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Self-contained bundle with everything a client needs to verify proofs
+//! generated with the [`BlindBidCircuit`].
+
+use super::{BlindBidCircuit, BlindBidPublicInputs, TRIM_SIZE};
+use crate::errors::BlindBidError;
+use core::borrow::Borrow;
+use dusk_bls12_381::BlsScalar;
+use dusk_plonk::commitment_scheme::kzg10::OpeningKey;
+use dusk_plonk::prelude::*;
+
+/// Number of scalars that the [`BlindBidPublicInputs`] occupy inside of the
+/// Public Inputs vector of the circuit.
+const PI_LEN: usize = 7;
+
+/// The BlindBidVerifierBundle packages everything that a verifier needs in
+/// order to check a proof generated with the [`BlindBidCircuit`], so thin
+/// clients don't need to hold the full Public Parameters nor compile the
+/// circuit.
+///
+/// It is produced once by a trusted party with
+/// [`BlindBidCircuit::export_verifier_bundle`] and distributed in its
+/// serialized form.
+#[cfg_attr(docsrs, doc(cfg(all(feature = "proof", feature = "canon"))))]
+#[derive(Debug, Clone)]
+pub struct BlindBidVerifierBundle {
+    /// Verifier key of the compiled circuit.
+    vk: VerifierKey,
+    /// Part of the Public Parameters needed to verify the proofs.
+    opening_key: OpeningKey,
+    /// Label used to initialize the transcript of the proofs.
+    label: &'static [u8],
+    /// Trim size of the Public Parameters used by the PLONK mechanism.
+    trim_size: usize,
+    /// Positions of the Public Inputs inside of the circuit.
+    pi_positions: [usize; PI_LEN],
+}
+
+impl<'a, const DEPTH: usize> BlindBidCircuit<'a, DEPTH> {
+    /// Compiles the circuit and exports the [`BlindBidVerifierBundle`] that
+    /// allows to verify the proofs generated with it and the provided
+    /// `label`.
+    ///
+    /// The bundle expects the Public Inputs of the default mode, so circuits
+    /// that keep the commitment private are rejected with
+    /// [`BlindBidError::PrivateCommitmentUnsupported`].
+    pub fn export_verifier_bundle(
+        &mut self,
+        pub_params: &PublicParameters,
+        label: &'static [u8],
+    ) -> Result<BlindBidVerifierBundle, BlindBidError> {
+        if self.private_commitment {
+            return Err(BlindBidError::PrivateCommitmentUnsupported);
+        }
+        self.check_trim_size(pub_params)?;
+        check_bundle_trim_size(self.trim_size)?;
+        let (_, vk) = self
+            .compile(pub_params)
+            .map_err(|_| BlindBidError::CircuitSynthesisFailed)?;
+
+        BlindBidVerifierBundle::from_circuit(self, pub_params, vk, label)
+    }
+}
+
+impl BlindBidVerifierBundle {
+    /// Assembles the bundle of an already compiled `circuit` whose Public
+    /// Input positions were already filled by its gadget.
+    pub(crate) fn from_circuit<const DEPTH: usize>(
+        circuit: &BlindBidCircuit<'_, DEPTH>,
+        pub_params: &PublicParameters,
+        vk: VerifierKey,
+        label: &'static [u8],
+    ) -> Result<Self, BlindBidError> {
+        let (_, opening_key) = pub_params
+            .trim(circuit.get_trim_size())
+            .map_err(|_| BlindBidError::TrimSizeTooLarge {
+                requested: circuit.get_trim_size(),
+                available: pub_params.max_degree(),
+            })?;
+
+        let mut pi_positions = [0usize; PI_LEN];
+        circuit
+            .get_pi_positions()
+            .iter()
+            .flat_map(|pi| match pi {
+                PublicInput::BlsScalar(_, pos) => vec![*pos],
+                PublicInput::JubJubScalar(_, pos) => vec![*pos],
+                PublicInput::AffinePoint(_, x, y) => vec![*x, *y],
+            })
+            .zip(pi_positions.iter_mut())
+            .for_each(|(pos, slot)| *slot = pos);
+
+        Ok(BlindBidVerifierBundle {
+            vk,
+            opening_key,
+            label,
+            trim_size: circuit.get_trim_size(),
+            pi_positions,
+        })
+    }
+}
+
+impl BlindBidVerifierBundle {
+    /// Returns the label used to initialize the transcript of the proofs.
+    pub fn label(&self) -> &'static [u8] {
+        self.label
+    }
+
+    /// Verifies a proof against its Public Inputs.
+    pub fn verify(
+        &self,
+        proof: &Proof,
+        pi: &BlindBidPublicInputs,
+    ) -> Result<(), BlindBidError> {
+        let verifier = self.verifier();
+        let mut dense_pi = vec![BlsScalar::zero(); self.trim_size];
+        self.verify_with(&verifier, &mut dense_pi, proof, pi)
+    }
+
+    /// Verifies a batch of proofs against their Public Inputs.
+    ///
+    /// The PLONK verifier and the buffer of the Public Inputs are set up
+    /// once and shared by all of the proofs of the batch, which are still
+    /// verified one after the other. The index of the
+    /// first proof that does not verify is returned inside of a
+    /// [`BlindBidError::BatchVerificationFailed`].
+    ///
+    /// The proofs can be provided by value or by reference.
+    pub fn verify_batch<P: Borrow<Proof>>(
+        &self,
+        items: &[(P, BlindBidPublicInputs)],
+    ) -> Result<(), BlindBidError> {
+        let verifier = self.verifier();
+        let mut dense_pi = vec![BlsScalar::zero(); self.trim_size];
+        items
+            .iter()
+            .enumerate()
+            .try_for_each(|(index, (proof, pi))| {
+                self.verify_with(&verifier, &mut dense_pi, proof.borrow(), pi)
+                    .map_err(|_| BlindBidError::BatchVerificationFailed {
+                        index,
+                    })
+            })
+    }
+
+    fn verifier(&self) -> Verifier {
+        let mut verifier = Verifier::new(self.label);
+        verifier.verifier_key = Some(self.vk.clone());
+        verifier
+    }
+
+    /// Verifies `proof` with a `verifier` built by
+    /// [`BlindBidVerifierBundle::verifier`], reusing `dense_pi` as the
+    /// Public Inputs vector. Only the positions of the Public Inputs are
+    /// ever written, so the rest of the buffer stays zeroed across calls.
+    fn verify_with(
+        &self,
+        verifier: &Verifier,
+        dense_pi: &mut [BlsScalar],
+        proof: &Proof,
+        pi: &BlindBidPublicInputs,
+    ) -> Result<(), BlindBidError> {
+        let values = [
+            pi.root,
+            pi.bid_hash,
+            pi.commitment.get_x(),
+            pi.commitment.get_y(),
+            pi.hashed_secret,
+            pi.prover_id,
+            pi.score,
+        ];
+        self.pi_positions
+            .iter()
+            .zip(values.iter())
+            .for_each(|(pos, value)| dense_pi[*pos] = -value);
+
+        verifier
+            .verify(proof, &self.opening_key, dense_pi)
+            .map_err(|_| BlindBidError::ProofVerificationFailed)
+    }
+
+    /// Serializes the bundle.
+    ///
+    /// Every variable-length component is prefixed with its length encoded
+    /// as a little-endian `u32`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![];
+        let mut write_chunk = |chunk: &[u8]| {
+            bytes.extend_from_slice(&(chunk.len() as u32).to_le_bytes());
+            bytes.extend_from_slice(chunk);
+        };
+        write_chunk(&self.vk.to_bytes()[..]);
+        write_chunk(&self.opening_key.to_bytes()[..]);
+        write_chunk(self.label);
+
+        bytes.extend_from_slice(&(self.trim_size as u64).to_le_bytes());
+        self.pi_positions.iter().for_each(|pos| {
+            bytes.extend_from_slice(&(*pos as u64).to_le_bytes())
+        });
+        bytes
+    }
+
+    /// Deserializes a bundle generated with
+    /// [`BlindBidVerifierBundle::to_bytes`] for the transcript `label`.
+    ///
+    /// The transcript of the proofs requires a `'static` label, so it is
+    /// provided by the caller and compared with the one stored in the
+    /// bundle, failing with [`BlindBidError::TranscriptLabelMismatch`] if
+    /// they differ.
+    ///
+    /// Bundles with a trim size above the one of [`verify_blind_bid`]
+    /// (`1 << 15`) are rejected with [`BlindBidError::TrimSizeTooLarge`]
+    /// before allocating anything for them, and truncated or corrupt bundles
+    /// with [`BlindBidError::MalformedBundle`].
+    ///
+    /// [`verify_blind_bid`]: crate::verify_blind_bid
+    pub fn from_bytes(
+        bytes: &[u8],
+        label: &'static [u8],
+    ) -> Result<Self, BlindBidError> {
+        let mut buf = bytes;
+        let vk = read_chunk(&mut buf)
+            .and_then(|chunk| VerifierKey::from_bytes(chunk).ok())
+            .ok_or(BlindBidError::MalformedBundle)?;
+        let opening_key = read_chunk(&mut buf)
+            .and_then(|chunk| OpeningKey::from_bytes(chunk).ok())
+            .ok_or(BlindBidError::MalformedBundle)?;
+        let stored_label =
+            read_chunk(&mut buf).ok_or(BlindBidError::MalformedBundle)?;
+        if stored_label != label {
+            return Err(BlindBidError::TranscriptLabelMismatch);
+        }
+
+        let trim_size =
+            read_u64(&mut buf).ok_or(BlindBidError::MalformedBundle)? as usize;
+        check_bundle_trim_size(trim_size)?;
+        let mut pi_positions = [0usize; PI_LEN];
+        for pos in pi_positions.iter_mut() {
+            *pos = read_u64(&mut buf).ok_or(BlindBidError::MalformedBundle)?
+                as usize;
+            if *pos >= trim_size {
+                return Err(BlindBidError::MalformedBundle);
+            }
+        }
+
+        Ok(BlindBidVerifierBundle {
+            vk,
+            opening_key,
+            label,
+            trim_size,
+            pi_positions,
+        })
+    }
+}
+
+/// Checks that a bundle can be verified with a Public Inputs vector of
+/// `trim_size` scalars, which is allocated on every verification.
+fn check_bundle_trim_size(trim_size: usize) -> Result<(), BlindBidError> {
+    if trim_size > TRIM_SIZE {
+        return Err(BlindBidError::TrimSizeTooLarge {
+            requested: trim_size,
+            available: TRIM_SIZE,
+        });
+    }
+    Ok(())
+}
+
+/// Reads a little-endian `u64` from the start of `buf`, or returns `None` if
+/// it is too short.
+pub(super) fn read_u64(buf: &mut &[u8]) -> Option<u64> {
+    if buf.len() < 8 {
+        return None;
+    }
+    let mut word = [0u8; 8];
+    word.copy_from_slice(&buf[..8]);
+    *buf = &buf[8..];
+    Some(u64::from_le_bytes(word))
+}
+
+/// Reads a chunk prefixed with its length as a little-endian `u32` from the
+/// start of `buf`, or returns `None` if it is too short.
+pub(super) fn read_chunk<'b>(buf: &mut &'b [u8]) -> Option<&'b [u8]> {
+    if buf.len() < 4 {
+        return None;
+    }
+    let mut len = [0u8; 4];
+    len.copy_from_slice(&buf[..4]);
+    let len = u32::from_le_bytes(len) as usize;
+    if buf.len() < 4 + len {
+        return None;
+    }
+    let chunk = &buf[4..4 + len];
+    *buf = &buf[4 + len..];
+    Some(chunk)
+}
@@ -0,0 +1,111 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Validation of the set of Bids that a Block Generator includes as
+//! candidates for a consensus round.
+
+use super::{verify_blind_bids_batch, BlindBidPublicInputs};
+use crate::bid::Bid;
+use crate::errors::{BlindBidError, CandidateRejection};
+use core::borrow::Borrow;
+use dusk_bls12_381::BlsScalar;
+use dusk_plonk::prelude::*;
+
+/// Checks that a set of `(Bid, Proof, PublicInputs)` entries forms a valid,
+/// conflict-free candidate set for the consensus round `round`.
+///
+/// Each entry is checked **in order** for:
+/// 1. The Bid not being expired nor non-eligible at `round`.
+/// 2. The Public Inputs referring to the Bid they're paired with.
+/// 3. The Bid and the prover ID not being already used by a previous entry.
+/// 4. The proof being valid for the provided Public Inputs.
+///
+/// The first entry that fails any of the checks makes the whole set invalid,
+/// and its index is returned together with the reason of the rejection
+/// inside of a [`BlindBidError::InvalidCandidate`].
+///
+/// The checks 1. to 3. are performed first for all of the entries. Then the
+/// proofs of the entries before the first one that fails them are verified
+/// with [`verify_blind_bids_batch`], so the Public Parameters are trimmed and
+/// the circuit is synthesized once for the whole set. The reported entry is
+/// the same one that checking the entries one by one would report.
+///
+/// The proofs can be provided by value or by reference.
+#[cfg_attr(docsrs, doc(cfg(all(feature = "proof", feature = "canon"))))]
+pub fn validate_candidate_set<P: Borrow<Proof>>(
+    pub_params: &PublicParameters,
+    vk: &VerifierKey,
+    label: &'static [u8],
+    entries: &[(Bid, P, BlindBidPublicInputs)],
+    round: u64,
+) -> Result<(), BlindBidError> {
+    let rejected = first_conflict(entries, round);
+    let checked = rejected.map_or(entries.len(), |(index, _)| index);
+
+    let items: Vec<(&Proof, BlindBidPublicInputs)> = entries[..checked]
+        .iter()
+        .map(|(_, proof, pi)| (proof.borrow(), *pi))
+        .collect();
+    if !items.is_empty() {
+        verify_blind_bids_batch(pub_params, vk, label, &items).map_err(
+            |err| match err {
+                BlindBidError::BatchVerificationFailed { index } => {
+                    BlindBidError::InvalidCandidate {
+                        index,
+                        reason: CandidateRejection::InvalidProof,
+                    }
+                }
+                err => err,
+            },
+        )?;
+    }
+
+    match rejected {
+        Some((index, reason)) => {
+            Err(BlindBidError::InvalidCandidate { index, reason })
+        }
+        None => Ok(()),
+    }
+}
+
+/// Returns the index of the first entry that fails the checks 1. to 3. of
+/// [`validate_candidate_set`] together with the reason of its rejection.
+fn first_conflict<P>(
+    entries: &[(Bid, P, BlindBidPublicInputs)],
+    round: u64,
+) -> Option<(usize, CandidateRejection)> {
+    let mut seen_bids: Vec<BlsScalar> = Vec::with_capacity(entries.len());
+    let mut seen_prover_ids: Vec<BlsScalar> = Vec::with_capacity(entries.len());
+
+    for (index, (bid, _, pi)) in entries.iter().enumerate() {
+        if bid.is_expired(round) {
+            return Some((index, CandidateRejection::ExpiredBid));
+        }
+        if !bid.is_eligible(round) {
+            return Some((index, CandidateRejection::NonEligibleBid));
+        }
+
+        let bid_hash = bid.hash();
+        if pi.bid_hash != bid_hash
+            || pi.commitment != bid.commitment()
+            || pi.hashed_secret != bid.hashed_secret()
+        {
+            return Some((index, CandidateRejection::PublicInputsMismatch));
+        }
+
+        if seen_bids.contains(&bid_hash) {
+            return Some((index, CandidateRejection::DuplicateBid));
+        }
+        if seen_prover_ids.contains(&pi.prover_id) {
+            return Some((index, CandidateRejection::DuplicateProverId));
+        }
+
+        seen_bids.push(bid_hash);
+        seen_prover_ids.push(pi.prover_id);
+    }
+
+    None
+}
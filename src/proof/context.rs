@@ -0,0 +1,110 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Transcript context shared by the provers and the verifiers of the
+//! [`BlindBidCircuit`].
+
+use super::{
+    verify_blind_bid, BlindBidCircuit, BlindBidPublicInputs,
+    BlindBidVerifierBundle,
+};
+use crate::errors::BlindBidError;
+use anyhow::Result;
+use dusk_plonk::prelude::*;
+
+/// Transcript label used by the [`ProofContext::default`] context.
+pub const BLINDBID_TRANSCRIPT_LABEL: &[u8] = b"dusk-blindbid";
+
+/// The ProofContext holds the label used to initialize the transcript of the
+/// Blind Bid proofs.
+///
+/// Proofs generated with a label only verify with the same label, and a
+/// mismatch is otherwise indistinguishable from an invalid proof. Generating
+/// and verifying the proofs through the same context (or through contexts
+/// built from the same constant) prevents the labels from diverging.
+#[cfg_attr(docsrs, doc(cfg(all(feature = "proof", feature = "canon"))))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProofContext {
+    label: &'static [u8],
+}
+
+impl Default for ProofContext {
+    fn default() -> Self {
+        ProofContext::new(BLINDBID_TRANSCRIPT_LABEL)
+    }
+}
+
+impl ProofContext {
+    /// Creates a context that uses `label` as transcript label.
+    pub const fn new(label: &'static [u8]) -> Self {
+        ProofContext { label }
+    }
+
+    /// Returns the transcript label of the context.
+    pub fn label(&self) -> &'static [u8] {
+        self.label
+    }
+
+    /// Checks that `label` is the transcript label of the context, returning
+    /// [`BlindBidError::TranscriptLabelMismatch`] otherwise.
+    pub fn check_label(&self, label: &[u8]) -> Result<(), BlindBidError> {
+        if self.label != label {
+            return Err(BlindBidError::TranscriptLabelMismatch);
+        }
+        Ok(())
+    }
+
+    /// Generates a proof for the `circuit` with the transcript label of the
+    /// context.
+    pub fn gen_proof<const DEPTH: usize>(
+        &self,
+        circuit: &mut BlindBidCircuit<'_, DEPTH>,
+        pub_params: &PublicParameters,
+        pk: &ProverKey,
+    ) -> Result<Proof> {
+        circuit.gen_proof(pub_params, pk, self.label)
+    }
+
+    /// Verifies a proof with the transcript label of the context.
+    pub fn verify(
+        &self,
+        pub_params: &PublicParameters,
+        vk: &VerifierKey,
+        proof: &Proof,
+        pi: &BlindBidPublicInputs,
+    ) -> Result<(), BlindBidError> {
+        verify_blind_bid(pub_params, vk, self.label, proof, pi)
+    }
+
+    /// Verifies a proof with a [`BlindBidVerifierBundle`], failing with
+    /// [`BlindBidError::TranscriptLabelMismatch`] if the bundle was exported
+    /// for a different transcript label.
+    pub fn verify_with_bundle(
+        &self,
+        bundle: &BlindBidVerifierBundle,
+        proof: &Proof,
+        pi: &BlindBidPublicInputs,
+    ) -> Result<(), BlindBidError> {
+        self.check_label(bundle.label())?;
+        bundle.verify(proof, pi)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn label_mismatch_is_reported() {
+        let context = ProofContext::default();
+        assert_eq!(context.label(), BLINDBID_TRANSCRIPT_LABEL);
+        assert!(context.check_label(BLINDBID_TRANSCRIPT_LABEL).is_ok());
+        assert!(matches!(
+            context.check_label(b"CorrectBid"),
+            Err(BlindBidError::TranscriptLabelMismatch)
+        ));
+    }
+}
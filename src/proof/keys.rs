@@ -0,0 +1,225 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Compiled keys of the [`BlindBidCircuit`].
+
+use super::bundle::{read_chunk, read_u64};
+use super::{BlindBidCircuit, BID_TREE_DEPTH};
+use crate::errors::BlindBidError;
+use dusk_plonk::prelude::*;
+use dusk_poseidon::tree::PoseidonBranch;
+
+/// Version of the serialization format of the [`BlindBidKeys`].
+const KEYS_VERSION: u8 = 1;
+
+/// The BlindBidKeys hold the [`ProverKey`] and the [`VerifierKey`] of the
+/// [`BlindBidCircuit`] compiled for a given `trim_size`.
+///
+/// The keys only depend on the Public Parameters and the `trim_size`, so
+/// long-running provers can compile them once and persist them with
+/// [`BlindBidKeys::to_bytes`] instead of compiling the circuit every time.
+#[cfg_attr(docsrs, doc(cfg(all(feature = "proof", feature = "canon"))))]
+#[derive(Debug, Clone)]
+pub struct BlindBidKeys {
+    /// Prover key of the compiled circuit.
+    pk: ProverKey,
+    /// Verifier key of the compiled circuit.
+    vk: VerifierKey,
+    /// Trim size of the Public Parameters the circuit was compiled with.
+    trim_size: usize,
+}
+
+impl BlindBidKeys {
+    /// Compiles the [`BlindBidCircuit`] for a tree of [`BID_TREE_DEPTH`]
+    /// with the Public Parameters trimmed to `trim_size`.
+    pub fn compile(
+        pub_params: &PublicParameters,
+        trim_size: usize,
+    ) -> Result<Self, BlindBidError> {
+        // The keys don't depend on the witnesses of the circuit, so we just
+        // fill them with placeholder values.
+        let branch = PoseidonBranch::<BID_TREE_DEPTH>::default();
+        let mut circuit = BlindBidCircuit::for_keygen(&branch, trim_size);
+        circuit.check_trim_size(pub_params)?;
+        let (pk, vk) = circuit
+            .compile(pub_params)
+            .map_err(|_| BlindBidError::CircuitSynthesisFailed)?;
+
+        Ok(BlindBidKeys { pk, vk, trim_size })
+    }
+
+    /// Returns the prover key of the compiled circuit.
+    pub fn prover_key(&self) -> &ProverKey {
+        &self.pk
+    }
+
+    /// Returns the verifier key of the compiled circuit.
+    pub fn verifier_key(&self) -> &VerifierKey {
+        &self.vk
+    }
+
+    /// Returns the trim size the circuit was compiled with.
+    pub fn trim_size(&self) -> usize {
+        self.trim_size
+    }
+
+    /// Serializes the keys.
+    ///
+    /// The serialization starts with a version byte and the trim size
+    /// encoded as a little-endian `u64`, followed by both keys prefixed with
+    /// their length encoded as a little-endian `u32`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![KEYS_VERSION];
+        bytes.extend_from_slice(&(self.trim_size as u64).to_le_bytes());
+        let mut write_chunk = |chunk: &[u8]| {
+            bytes.extend_from_slice(&(chunk.len() as u32).to_le_bytes());
+            bytes.extend_from_slice(chunk);
+        };
+        write_chunk(&self.pk.to_bytes()[..]);
+        write_chunk(&self.vk.to_bytes()[..]);
+        bytes
+    }
+
+    /// Deserializes the keys generated with [`BlindBidKeys::to_bytes`].
+    ///
+    /// Fails with [`BlindBidError::KeyVersionMismatch`] if they were
+    /// serialized with another version of the format, with
+    /// [`BlindBidError::TrimSizeMismatch`] if they weren't compiled for the
+    /// `expected_trim_size`, and with [`BlindBidError::MalformedKeys`] if the
+    /// bytes are truncated or corrupt.
+    pub fn from_bytes(
+        bytes: &[u8],
+        expected_trim_size: usize,
+    ) -> Result<Self, BlindBidError> {
+        let (version, mut buf) =
+            bytes.split_first().ok_or(BlindBidError::MalformedKeys)?;
+        if *version != KEYS_VERSION {
+            return Err(BlindBidError::KeyVersionMismatch {
+                expected: KEYS_VERSION,
+                found: *version,
+            });
+        }
+        let trim_size =
+            read_u64(&mut buf).ok_or(BlindBidError::MalformedKeys)? as usize;
+        if trim_size != expected_trim_size {
+            return Err(BlindBidError::TrimSizeMismatch {
+                expected: expected_trim_size,
+                found: trim_size,
+            });
+        }
+        let pk = ProverKey::from_bytes(
+            read_chunk(&mut buf).ok_or(BlindBidError::MalformedKeys)?,
+        )
+        .map_err(|_| BlindBidError::MalformedKeys)?;
+        let vk = VerifierKey::from_bytes(
+            read_chunk(&mut buf).ok_or(BlindBidError::MalformedKeys)?,
+        )
+        .map_err(|_| BlindBidError::MalformedKeys)?;
+
+        Ok(BlindBidKeys { pk, vk, trim_size })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lazy_static::lazy_static;
+
+    lazy_static! {
+        // The setup is the slowest part of the tests, so it's shared by all
+        // of them.
+        static ref PUB_PARAMS: PublicParameters =
+            PublicParameters::setup(1 << 17, &mut rand::thread_rng())
+                .expect("The setup of the Public Parameters failed");
+    }
+
+    #[test]
+    fn keys_serialization_roundtrip() -> Result<(), BlindBidError> {
+        let keys = BlindBidKeys::compile(&PUB_PARAMS, 1 << 15)?;
+        let bytes = keys.to_bytes();
+
+        let loaded = BlindBidKeys::from_bytes(&bytes, 1 << 15)?;
+        assert_eq!(loaded.trim_size(), keys.trim_size());
+        assert_eq!(
+            loaded.verifier_key().to_bytes()[..],
+            keys.verifier_key().to_bytes()[..]
+        );
+        assert_eq!(
+            loaded.prover_key().to_bytes()[..],
+            keys.prover_key().to_bytes()[..]
+        );
+
+        assert!(matches!(
+            BlindBidKeys::from_bytes(&bytes, 1 << 16),
+            Err(BlindBidError::TrimSizeMismatch {
+                expected: 65536,
+                found: 32768
+            })
+        ));
+        assert!(matches!(
+            BlindBidKeys::from_bytes(&bytes[..bytes.len() - 1], 1 << 15),
+            Err(BlindBidError::MalformedKeys)
+        ));
+        assert!(matches!(
+            BlindBidKeys::from_bytes(&[], 1 << 15),
+            Err(BlindBidError::MalformedKeys)
+        ));
+
+        let mut unknown_version = bytes.clone();
+        unknown_version[0] = KEYS_VERSION + 1;
+        assert!(matches!(
+            BlindBidKeys::from_bytes(&unknown_version, 1 << 15),
+            Err(BlindBidError::KeyVersionMismatch { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn oversized_trim_size() -> Result<(), BlindBidError> {
+        assert!(matches!(
+            BlindBidKeys::compile(&PUB_PARAMS, 1 << 18),
+            Err(BlindBidError::TrimSizeTooLarge {
+                requested: 262144,
+                ..
+            })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn undersized_trim_size() -> Result<(), BlindBidError> {
+        let minimum =
+            BlindBidCircuit::<'_, BID_TREE_DEPTH>::recommended_trim_size()?;
+        assert!(minimum.is_power_of_two() && minimum <= 1 << 15);
+        assert!(matches!(
+            BlindBidKeys::compile(&PUB_PARAMS, 1 << 10),
+            Err(BlindBidError::TrimSizeTooSmall {
+                requested: 1024,
+                minimum: found,
+            }) if found == minimum
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn keys_file_roundtrip() -> Result<(), BlindBidError> {
+        let keys = BlindBidKeys::compile(&PUB_PARAMS, 1 << 15)?;
+
+        let path = std::env::temp_dir()
+            .join(format!("blindbid-keys-{}.bin", std::process::id()));
+        std::fs::write(&path, keys.to_bytes())
+            .map_err(|_| BlindBidError::IOError)?;
+        let bytes = std::fs::read(&path).map_err(|_| BlindBidError::IOError);
+        std::fs::remove_file(&path).map_err(|_| BlindBidError::IOError)?;
+
+        let loaded = BlindBidKeys::from_bytes(&bytes?, 1 << 15)?;
+        assert_eq!(
+            loaded.prover_key().to_bytes()[..],
+            keys.prover_key().to_bytes()[..]
+        );
+        Ok(())
+    }
+}
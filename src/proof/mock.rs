@@ -0,0 +1,156 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Mock proofs of the [`BlindBidCircuit`] for testing verifiers.
+
+use super::{BlindBidCircuit, BlindBidPublicInputs};
+use crate::bid::compute_prover_id;
+use crate::errors::BlindBidError;
+use crate::hasher::{BidHasher, DefaultHasher};
+use dusk_bls12_381::BlsScalar;
+use dusk_plonk::prelude::*;
+
+/// A MockProof stands for a proof of the [`BlindBidCircuit`] in the tests of
+/// the verifiers.
+///
+/// It's just the hash of the Public Inputs the circuit exposes, in order. So
+/// it's cheap and deterministic, and verifying it with Public Inputs that are
+/// missing, transposed or different fails as a real proof would.
+///
+/// **Mock proofs provide no soundness at all**: anyone can generate one for
+/// any set of Public Inputs, without knowing any Bid. They must never be
+/// accepted outside of tests.
+#[cfg_attr(docsrs, doc(cfg(feature = "mock")))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MockProof(BlsScalar);
+
+impl<'a, const DEPTH: usize> BlindBidCircuit<'a, DEPTH> {
+    /// Generates a [`MockProof`] for the Public Inputs that the circuit
+    /// exposes for its witnesses.
+    ///
+    /// The witnesses are not checked: see [`MockProof`].
+    #[cfg_attr(docsrs, doc(cfg(feature = "mock")))]
+    pub fn gen_mock_proof(&self) -> MockProof {
+        let pi = BlindBidPublicInputs {
+            root: *self.branch.root(),
+            bid_hash: self.bid.hash(),
+            commitment: self.bid.commitment(),
+            hashed_secret: self.bid.hashed_secret(),
+            prover_id: compute_prover_id(
+                self.secret_k,
+                self.seed,
+                self.latest_consensus_round,
+                self.latest_consensus_step,
+            ),
+            score: self.score.value(),
+        };
+        let pi = match self.private_commitment {
+            true => pi.to_public_inputs_without_commitment(),
+            false => pi.to_public_inputs(),
+        };
+        MockProof(hash_public_inputs(&pi))
+    }
+}
+
+/// Verifies a [`MockProof`] against the Public Inputs, in the order expected
+/// by the [`BlindBidCircuit`], failing with
+/// [`BlindBidError::ProofVerificationFailed`] if they're not the ones the
+/// proof was generated for.
+#[cfg_attr(docsrs, doc(cfg(feature = "mock")))]
+pub fn verify_mock_proof(
+    proof: &MockProof,
+    pi: &[PublicInput],
+) -> Result<(), BlindBidError> {
+    if hash_public_inputs(pi) != proof.0 {
+        return Err(BlindBidError::ProofVerificationFailed);
+    }
+    Ok(())
+}
+
+/// Hashes the values of the Public Inputs in order.
+fn hash_public_inputs(pi: &[PublicInput]) -> BlsScalar {
+    let scalars: Vec<BlsScalar> = pi
+        .iter()
+        .flat_map(|pi| match pi {
+            PublicInput::BlsScalar(s, _) => vec![*s],
+            PublicInput::JubJubScalar(s, _) => vec![(*s).into()],
+            PublicInput::AffinePoint(p, _, _) => vec![p.get_x(), p.get_y()],
+        })
+        .collect();
+    DefaultHasher::hash(&scalars)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bid::{Bid, Score};
+    use crate::V_RAW_MIN;
+    use dusk_jubjub::{JubJubAffine, GENERATOR_EXTENDED};
+    use dusk_pki::{PublicSpendKey, SecretSpendKey};
+    use dusk_poseidon::tree::PoseidonBranch;
+
+    #[test]
+    fn mock_proofs_bind_the_public_inputs() {
+        let mut rng = rand::thread_rng();
+        let pk_r = PublicSpendKey::from(SecretSpendKey::random(&mut rng));
+        let stealth_addr = pk_r.gen_stealth_address(&JubJubScalar::one());
+        let secret: JubJubAffine =
+            (GENERATOR_EXTENDED * JubJubScalar::from(7u64)).into();
+        let secret_k = BlsScalar::random(&mut rng);
+        let bid = Bid::new(
+            &mut rng,
+            &stealth_addr,
+            &JubJubScalar::from(V_RAW_MIN),
+            &secret,
+            secret_k,
+            u64::MAX,
+            u64::MAX,
+        )
+        .expect("Bid creation error");
+        let branch = PoseidonBranch::<17>::default();
+        let score = Score::compute(
+            &bid,
+            &secret,
+            secret_k,
+            *branch.root(),
+            BlsScalar::one(),
+            3,
+            1,
+        )
+        .expect("Score computation error");
+
+        let circuit = BlindBidCircuit::from_inputs(
+            &bid,
+            score,
+            &secret,
+            secret_k,
+            &branch,
+            BlsScalar::one(),
+            3,
+            1,
+        );
+        let proof = circuit.gen_mock_proof();
+        let prover_id = bid.generate_prover_id(
+            secret_k,
+            BlsScalar::one(),
+            BlsScalar::from(3u64),
+            BlsScalar::from(1u64),
+        );
+        let pi = BlindBidPublicInputs::from_bid(
+            &bid,
+            *branch.root(),
+            score.value(),
+            prover_id,
+        )
+        .to_public_inputs();
+        assert!(verify_mock_proof(&proof, &pi).is_ok());
+
+        let mut transposed = pi.clone();
+        transposed.swap(4, 5);
+        assert!(verify_mock_proof(&proof, &transposed).is_err());
+        assert!(verify_mock_proof(&proof, &pi[..5]).is_err());
+    }
+}
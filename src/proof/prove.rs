@@ -0,0 +1,80 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Proving entrypoint for the [`BlindBidCircuit`].
+
+use super::{BidOpening, BlindBidCircuit, BlindBidPublicInputs};
+use crate::bid::{Bid, Score};
+use crate::errors::BlindBidError;
+use dusk_bls12_381::BlsScalar;
+use dusk_jubjub::JubJubAffine;
+use dusk_plonk::prelude::*;
+
+/// Generates a proof of blindbid for `bid` at the given consensus round and
+/// step, returning it together with the Public Inputs needed to verify it.
+///
+/// This computes the [`Score`] and the prover ID of the Bid, fills the
+/// [`BlindBidCircuit`] with them and proves it with the provided
+/// `prover_key`, which has to be the one of a circuit compiled with the
+/// default trim size and for a tree of the same depth as `branch`.
+///
+/// `branch` is the Merkle Opening of the leaf of the Bid tree that contains
+/// `bid`, either a dense or a sparse one (see [`BidOpening`]), and `secret`
+/// the one that decrypts its value & blinder. The prover key has to be the
+/// one of a circuit compiled for the same kind of opening.
+#[cfg_attr(docsrs, doc(cfg(all(feature = "proof", feature = "canon"))))]
+#[allow(clippy::too_many_arguments)]
+pub fn prove_blind_bid<'a, const DEPTH: usize>(
+    pub_params: &PublicParameters,
+    prover_key: &ProverKey,
+    bid: &Bid,
+    secret: &JubJubAffine,
+    secret_k: BlsScalar,
+    branch: impl Into<BidOpening<'a, DEPTH>>,
+    seed: BlsScalar,
+    latest_consensus_round: u64,
+    latest_consensus_step: u64,
+    label: &'static [u8],
+) -> Result<(Proof, BlindBidPublicInputs), BlindBidError> {
+    let branch = branch.into();
+    let score = Score::compute(
+        bid,
+        secret,
+        secret_k,
+        *branch.root(),
+        seed,
+        latest_consensus_round,
+        latest_consensus_step,
+    )?;
+    let prover_id = bid.generate_prover_id(
+        secret_k,
+        seed,
+        BlsScalar::from(latest_consensus_round),
+        BlsScalar::from(latest_consensus_step),
+    );
+
+    let mut circuit = BlindBidCircuit::from_inputs(
+        bid,
+        score,
+        secret,
+        secret_k,
+        branch,
+        seed,
+        latest_consensus_round,
+        latest_consensus_step,
+    );
+    let proof = circuit
+        .gen_proof(pub_params, prover_key, label)
+        .map_err(|_| BlindBidError::ProofGenerationFailed)?;
+    let pi = BlindBidPublicInputs::from_bid(
+        bid,
+        *branch.root(),
+        score.value(),
+        prover_id,
+    );
+
+    Ok((proof, pi))
+}
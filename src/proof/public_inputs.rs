@@ -0,0 +1,206 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Public inputs of the [`BlindBidCircuit`](super::BlindBidCircuit).
+
+use crate::bid::Bid;
+use dusk_bls12_381::BlsScalar;
+use dusk_bytes::{DeserializableSlice, Serializable};
+use dusk_jubjub::JubJubAffine;
+use dusk_plonk::prelude::*;
+
+/// Set of Public Inputs used to verify a proof generated with the
+/// [`BlindBidCircuit`](super::BlindBidCircuit).
+#[cfg_attr(docsrs, doc(cfg(all(feature = "proof", feature = "canon"))))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BlindBidPublicInputs {
+    /// Merkle Tree Root.
+    pub root: BlsScalar,
+    /// Bid hashed with Poseidon sponge hash.
+    pub bid_hash: BlsScalar,
+    /// Bid commitment field.
+    pub commitment: JubJubAffine,
+    /// Hashed secret of the Bid.
+    pub hashed_secret: BlsScalar,
+    /// Prover ID.
+    pub prover_id: BlsScalar,
+    /// Score produced by the Bid.
+    pub score: BlsScalar,
+}
+
+impl Serializable<{ 5 * BlsScalar::SIZE + JubJubAffine::SIZE }>
+    for BlindBidPublicInputs
+{
+    type Error = dusk_bytes::Error;
+
+    #[allow(unused_must_use)]
+    fn to_bytes(&self) -> [u8; Self::SIZE] {
+        use dusk_bytes::Write;
+
+        let mut buf = [0u8; Self::SIZE];
+        let mut writer = &mut buf[..];
+        writer.write(&self.root.to_bytes());
+        writer.write(&self.bid_hash.to_bytes());
+        writer.write(&self.commitment.to_bytes());
+        writer.write(&self.hashed_secret.to_bytes());
+        writer.write(&self.prover_id.to_bytes());
+        writer.write(&self.score.to_bytes());
+        buf
+    }
+
+    fn from_bytes(buf: &[u8; Self::SIZE]) -> Result<Self, Self::Error> {
+        let mut buffer = &buf[..];
+        Ok(BlindBidPublicInputs {
+            root: BlsScalar::from_reader(&mut buffer)?,
+            bid_hash: BlsScalar::from_reader(&mut buffer)?,
+            commitment: JubJubAffine::from_reader(&mut buffer)?,
+            hashed_secret: BlsScalar::from_reader(&mut buffer)?,
+            prover_id: BlsScalar::from_reader(&mut buffer)?,
+            score: BlsScalar::from_reader(&mut buffer)?,
+        })
+    }
+}
+
+impl BlindBidPublicInputs {
+    /// Assembles the Public Inputs of a proof generated for `bid` from the
+    /// round-specific values that can't be derived from the Bid itself.
+    pub fn from_bid(
+        bid: &Bid,
+        root: BlsScalar,
+        score: BlsScalar,
+        prover_id: BlsScalar,
+    ) -> Self {
+        Self {
+            root,
+            bid_hash: bid.hash(),
+            commitment: bid.commitment(),
+            hashed_secret: bid.hashed_secret(),
+            prover_id,
+            score,
+        }
+    }
+
+    /// Returns the Public Inputs in the order expected by the
+    /// [`BlindBidCircuit`](super::BlindBidCircuit).
+    ///
+    /// Building the vector by hand is error-prone, since transposing two of
+    /// the scalars makes the verification fail without any further hint.
+    pub fn to_public_inputs(&self) -> Vec<PublicInput> {
+        vec![
+            PublicInput::BlsScalar(self.root, 0),
+            PublicInput::BlsScalar(self.bid_hash, 0),
+            PublicInput::AffinePoint(self.commitment, 0, 0),
+            PublicInput::BlsScalar(self.hashed_secret, 0),
+            PublicInput::BlsScalar(self.prover_id, 0),
+            PublicInput::BlsScalar(self.score, 0),
+        ]
+    }
+
+    /// Returns the Public Inputs of a proof generated with
+    /// [`BlindBidCircuit::with_private_commitment`](super::BlindBidCircuit::with_private_commitment),
+    /// which are the ones of
+    /// [`BlindBidPublicInputs::to_public_inputs`] without the commitment.
+    pub fn to_public_inputs_without_commitment(&self) -> Vec<PublicInput> {
+        vec![
+            PublicInput::BlsScalar(self.root, 0),
+            PublicInput::BlsScalar(self.bid_hash, 0),
+            PublicInput::BlsScalar(self.hashed_secret, 0),
+            PublicInput::BlsScalar(self.prover_id, 0),
+            PublicInput::BlsScalar(self.score, 0),
+        ]
+    }
+
+    /// Returns `true` if the prover ID bound to the proof is the `expected`
+    /// one. This allows to check which identity produced a proof.
+    pub fn prover_id_matches(&self, expected: BlsScalar) -> bool {
+        self.prover_id == expected
+    }
+}
+
+/// Rebuilds the Public Inputs vector of a proof generated for `bid`.
+///
+/// This allows verifiers that already know the Bid (from the tree or the
+/// network) to verify a proof without the prover shipping the full Public
+/// Inputs alongside it.
+#[cfg_attr(docsrs, doc(cfg(all(feature = "proof", feature = "canon"))))]
+pub fn reconstruct_public_inputs(
+    bid: &Bid,
+    branch_root: BlsScalar,
+    score_value: BlsScalar,
+    prover_id: BlsScalar,
+) -> Vec<PublicInput> {
+    BlindBidPublicInputs::from_bid(bid, branch_root, score_value, prover_id)
+        .to_public_inputs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::V_RAW_MIN;
+    use dusk_jubjub::GENERATOR_EXTENDED;
+    use dusk_pki::{PublicSpendKey, SecretSpendKey};
+
+    #[test]
+    fn prover_id_matching() {
+        let mut rng = rand::thread_rng();
+        let pk_r = PublicSpendKey::from(SecretSpendKey::random(&mut rng));
+        let secret = JubJubScalar::random(&mut rng);
+        let stealth_addr = pk_r.gen_stealth_address(&secret);
+        let secret: JubJubAffine = (GENERATOR_EXTENDED * secret).into();
+        let secret_k = BlsScalar::random(&mut rng);
+        let bid = Bid::new(
+            &mut rng,
+            &stealth_addr,
+            &JubJubScalar::from(V_RAW_MIN),
+            &secret,
+            secret_k,
+            u64::MAX,
+            u64::MAX,
+        )
+        .expect("Bid creation error");
+
+        let seed = BlsScalar::random(&mut rng);
+        let round = BlsScalar::from(50u64);
+        let step = BlsScalar::from(2u64);
+        let pi = BlindBidPublicInputs {
+            root: BlsScalar::random(&mut rng),
+            bid_hash: bid.hash(),
+            commitment: bid.commitment(),
+            hashed_secret: bid.hashed_secret(),
+            prover_id: bid.generate_prover_id(secret_k, seed, round, step),
+            score: BlsScalar::random(&mut rng),
+        };
+
+        assert!(pi.prover_id_matches(
+            bid.generate_prover_id(secret_k, seed, round, step)
+        ));
+        assert!(!pi.prover_id_matches(bid.generate_prover_id(
+            BlsScalar::random(&mut rng),
+            seed,
+            round,
+            step
+        )));
+    }
+
+    #[test]
+    fn public_inputs_serialization_roundtrip() {
+        let mut rng = rand::thread_rng();
+        let pi = BlindBidPublicInputs {
+            root: BlsScalar::random(&mut rng),
+            bid_hash: BlsScalar::random(&mut rng),
+            commitment: (GENERATOR_EXTENDED * JubJubScalar::random(&mut rng))
+                .into(),
+            hashed_secret: BlsScalar::random(&mut rng),
+            prover_id: BlsScalar::random(&mut rng),
+            score: BlsScalar::random(&mut rng),
+        };
+
+        let pi_bytes = pi.to_bytes();
+        let pi_from_bytes = BlindBidPublicInputs::from_bytes(&pi_bytes)
+            .expect("Invalid roundtrip");
+        assert_eq!(pi, pi_from_bytes)
+    }
+}
@@ -0,0 +1,130 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Long-lived provers and verifiers of the [`BlindBidCircuit`].
+
+use super::{verify_blind_bid, BlindBidCircuit, BlindBidPublicInputs};
+use crate::bid::{Bid, Score};
+use crate::errors::BlindBidError;
+use dusk_bls12_381::BlsScalar;
+use dusk_jubjub::JubJubAffine;
+use dusk_plonk::prelude::*;
+use dusk_poseidon::tree::PoseidonBranch;
+
+/// The BlindBidProver holds everything needed to generate proofs with the
+/// [`BlindBidCircuit`] besides its witnesses: the Public Parameters, the
+/// [`ProverKey`] and the transcript label.
+///
+/// The prover key has to be the one of a circuit compiled with the default
+/// trim size, e.g. with [`BlindBidKeys`](super::BlindBidKeys), so the proofs
+/// can be verified with a [`BlindBidVerifier`] of the same label.
+#[cfg_attr(docsrs, doc(cfg(all(feature = "proof", feature = "canon"))))]
+#[derive(Debug, Clone)]
+pub struct BlindBidProver<'a> {
+    pub_params: &'a PublicParameters,
+    pk: ProverKey,
+    label: &'static [u8],
+}
+
+impl<'a> BlindBidProver<'a> {
+    /// Creates a prover that generates its proofs with `pk` and `label`.
+    pub fn new(
+        pub_params: &'a PublicParameters,
+        pk: ProverKey,
+        label: &'static [u8],
+    ) -> Self {
+        BlindBidProver {
+            pub_params,
+            pk,
+            label,
+        }
+    }
+
+    /// Returns the transcript label of the prover.
+    pub fn label(&self) -> &'static [u8] {
+        self.label
+    }
+
+    /// Generates a proof of blindbid for `bid` and its `score` at the given
+    /// consensus round and step.
+    ///
+    /// Fails with [`BlindBidError::ExpiredBid`] or
+    /// [`BlindBidError::NonEligibleBid`] before proving if the Bid can't be
+    /// proven at `latest_consensus_round`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn prove<const DEPTH: usize>(
+        &self,
+        bid: &Bid,
+        score: Score,
+        secret: &JubJubAffine,
+        secret_k: BlsScalar,
+        branch: &PoseidonBranch<DEPTH>,
+        seed: BlsScalar,
+        latest_consensus_round: u64,
+        latest_consensus_step: u64,
+    ) -> Result<Proof, BlindBidError> {
+        if bid.is_expired(latest_consensus_round) {
+            return Err(BlindBidError::ExpiredBid);
+        }
+        if !bid.is_eligible(latest_consensus_round) {
+            return Err(BlindBidError::NonEligibleBid);
+        }
+
+        BlindBidCircuit::from_inputs(
+            bid,
+            score,
+            secret,
+            secret_k,
+            branch,
+            seed,
+            latest_consensus_round,
+            latest_consensus_step,
+        )
+        .gen_proof(self.pub_params, &self.pk, self.label)
+        .map_err(|_| BlindBidError::ProofGenerationFailed)
+    }
+}
+
+/// The BlindBidVerifier holds the Public Parameters, the [`VerifierKey`] and
+/// the transcript label needed to verify the proofs of a
+/// [`BlindBidProver`].
+#[cfg_attr(docsrs, doc(cfg(all(feature = "proof", feature = "canon"))))]
+#[derive(Debug, Clone)]
+pub struct BlindBidVerifier<'a> {
+    pub_params: &'a PublicParameters,
+    vk: VerifierKey,
+    label: &'static [u8],
+}
+
+impl<'a> BlindBidVerifier<'a> {
+    /// Creates a verifier that checks the proofs with `vk` and `label`.
+    pub fn new(
+        pub_params: &'a PublicParameters,
+        vk: VerifierKey,
+        label: &'static [u8],
+    ) -> Self {
+        BlindBidVerifier {
+            pub_params,
+            vk,
+            label,
+        }
+    }
+
+    /// Returns the transcript label of the verifier.
+    pub fn label(&self) -> &'static [u8] {
+        self.label
+    }
+
+    /// Verifies a proof against its Public Inputs, as
+    /// [`verify_blind_bid`] does.
+    pub fn verify(
+        &self,
+        proof: &Proof,
+        pi: &BlindBidPublicInputs,
+    ) -> Result<(), BlindBidError> {
+        verify_blind_bid(self.pub_params, &self.vk, self.label, proof, pi)
+    }
+}
@@ -0,0 +1,331 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Merkle Openings proven by the [`BlindBidCircuit`](super::BlindBidCircuit)
+//! and sparse Bid trees, keyed by the hash of the Bids instead of their
+//! insertion order.
+
+use crate::bid::Bid;
+use crate::errors::BlindBidError;
+use dusk_bls12_381::BlsScalar;
+use dusk_bytes::Serializable;
+use dusk_hades::{GadgetStrategy, ScalarStrategy, Strategy, WIDTH};
+use dusk_plonk::prelude::*;
+use dusk_poseidon::tree::PoseidonBranch;
+use std::collections::BTreeMap;
+
+/// Number of children of every node of the Bid trees. Each level of a key
+/// takes two of its bits.
+const ARITY: usize = WIDTH - 1;
+
+/// Merkle Opening of the leaf of the Bid tree that contains the Bid proven
+/// by the [`BlindBidCircuit`](super::BlindBidCircuit).
+///
+/// Both kinds of trees hash their nodes in the same way, but they place the
+/// Bids differently, which changes the meaning of the `pos` of a Bid:
+/// - In a dense tree the `pos` of a Bid is the index it was inserted at. The
+///   circuit doesn't constrain the position of the leaf.
+/// - In a [`SparseBidTree`] the position of a Bid is its key, derived from
+///   its hash, and the circuit constrains the path of the opening to it. The
+///   `pos` of the Bid is then just part of its data.
+///
+/// The kind of opening is part of the structure of the circuit, so the keys
+/// compiled for one of them don't verify proofs of the other one.
+#[cfg_attr(docsrs, doc(cfg(all(feature = "proof", feature = "canon"))))]
+#[derive(Debug, Clone, Copy)]
+pub enum BidOpening<'a, const DEPTH: usize> {
+    /// Branch of a dense Poseidon tree.
+    Dense(&'a PoseidonBranch<DEPTH>),
+    /// Branch of a [`SparseBidTree`].
+    Sparse(&'a SparseBranch<DEPTH>),
+}
+
+impl<'a, const DEPTH: usize> BidOpening<'a, DEPTH> {
+    /// Returns the root of the tree the opening belongs to.
+    pub fn root(&self) -> &'a BlsScalar {
+        match *self {
+            BidOpening::Dense(branch) => branch.root(),
+            BidOpening::Sparse(branch) => branch.root(),
+        }
+    }
+}
+
+impl<'a, const DEPTH: usize> From<&'a PoseidonBranch<DEPTH>>
+    for BidOpening<'a, DEPTH>
+{
+    fn from(branch: &'a PoseidonBranch<DEPTH>) -> Self {
+        BidOpening::Dense(branch)
+    }
+}
+
+impl<'a, const DEPTH: usize> From<&'a SparseBranch<DEPTH>>
+    for BidOpening<'a, DEPTH>
+{
+    fn from(branch: &'a SparseBranch<DEPTH>) -> Self {
+        BidOpening::Sparse(branch)
+    }
+}
+
+/// Sparse Merkle tree of arity 4 that stores every Bid at the leaf of its
+/// key: the lowest `2 · DEPTH` bits of [`Bid::hash`].
+///
+/// Only the non-empty nodes are stored. Empty children hash as zero, and
+/// every node is hashed as in the dense Poseidon trees: a permutation of its
+/// presence mask followed by its children.
+#[cfg_attr(docsrs, doc(cfg(all(feature = "proof", feature = "canon"))))]
+#[derive(Debug, Clone)]
+pub struct SparseBidTree<const DEPTH: usize> {
+    /// Non-empty nodes of every level, from the leaves (`0`) up to the root
+    /// (`DEPTH`), by their index within the level.
+    levels: Vec<BTreeMap<u64, BlsScalar>>,
+}
+
+impl<const DEPTH: usize> Default for SparseBidTree<DEPTH> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const DEPTH: usize> SparseBidTree<DEPTH> {
+    /// Generates an empty tree.
+    ///
+    /// # Panics
+    /// If `DEPTH` is above 32, since the keys wouldn't fit in a `u64`.
+    pub fn new() -> Self {
+        assert!(DEPTH <= 32, "sparse trees are limited to 64-bit keys");
+        SparseBidTree {
+            levels: vec![BTreeMap::new(); DEPTH + 1],
+        }
+    }
+
+    /// Returns the key of the leaf for the Bid hash `bid_hash`: its lowest
+    /// `2 · DEPTH` bits, read as a little-endian integer.
+    pub fn key(bid_hash: &BlsScalar) -> u64 {
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&bid_hash.to_bytes()[..8]);
+        let key = u64::from_le_bytes(bytes);
+        match 2 * DEPTH {
+            64 => key,
+            bits => key & ((1 << bits) - 1),
+        }
+    }
+
+    /// Root of the tree.
+    pub fn root(&self) -> BlsScalar {
+        self.levels[DEPTH]
+            .get(&0)
+            .copied()
+            .unwrap_or_else(BlsScalar::zero)
+    }
+
+    /// Stores `bid` at its key, returning the key.
+    ///
+    /// Storing a Bid that is already in the tree does nothing, while keys
+    /// already used by another Bid are rejected with
+    /// [`BlindBidError::SparseKeyTaken`], leaving the tree untouched.
+    pub fn insert(&mut self, bid: &Bid) -> Result<u64, BlindBidError> {
+        let leaf = bid.hash();
+        let key = Self::key(&leaf);
+        match self.levels[0].get(&key) {
+            Some(stored) if *stored == leaf => return Ok(key),
+            Some(_) => return Err(BlindBidError::SparseKeyTaken { key }),
+            None => (),
+        }
+
+        self.levels[0].insert(key, leaf);
+        let mut index = key;
+        for depth in 0..DEPTH {
+            let parent = index / ARITY as u64;
+            let node = hash_level(&self.level(depth, parent));
+            self.levels[depth + 1].insert(parent, node);
+            index = parent;
+        }
+        Ok(key)
+    }
+
+    /// Returns the branch that opens the leaf of the Bid hash `bid_hash`, or
+    /// `None` if it isn't stored in the tree.
+    pub fn branch(&self, bid_hash: &BlsScalar) -> Option<SparseBranch<DEPTH>> {
+        let key = Self::key(bid_hash);
+        if self.levels[0].get(&key) != Some(bid_hash) {
+            return None;
+        }
+
+        let mut index = key;
+        let levels = (0..DEPTH)
+            .map(|depth| {
+                index /= ARITY as u64;
+                self.level(depth, index)
+            })
+            .collect();
+        Some(SparseBranch {
+            levels,
+            root: self.root(),
+        })
+    }
+
+    /// Presence mask followed by the children of the node `parent` of the
+    /// level `depth + 1`.
+    fn level(&self, depth: usize, parent: u64) -> [BlsScalar; WIDTH] {
+        let mut level = [BlsScalar::zero(); WIDTH];
+        let mut mask = 0u64;
+        (0..ARITY).for_each(|i| {
+            let index = parent * ARITY as u64 + i as u64;
+            if let Some(child) = self.levels[depth].get(&index) {
+                mask |= 1 << i;
+                level[i + 1] = *child;
+            }
+        });
+        level[0] = BlsScalar::from(mask);
+        level
+    }
+}
+
+/// Merkle Opening of a leaf of a [`SparseBidTree`].
+#[cfg_attr(docsrs, doc(cfg(all(feature = "proof", feature = "canon"))))]
+#[derive(Debug, Clone)]
+pub struct SparseBranch<const DEPTH: usize> {
+    /// Presence mask and children of the nodes of the path, from the parent
+    /// of the leaf up to the root.
+    levels: Vec<[BlsScalar; WIDTH]>,
+    /// Root of the tree.
+    root: BlsScalar,
+}
+
+impl<const DEPTH: usize> Default for SparseBranch<DEPTH> {
+    fn default() -> Self {
+        SparseBranch {
+            levels: vec![[BlsScalar::zero(); WIDTH]; DEPTH],
+            root: BlsScalar::zero(),
+        }
+    }
+}
+
+impl<const DEPTH: usize> SparseBranch<DEPTH> {
+    /// Root of the tree the branch belongs to.
+    pub fn root(&self) -> &BlsScalar {
+        &self.root
+    }
+
+    /// Checks natively that the branch opens `leaf` at its key up to the
+    /// root, as the [`BlindBidCircuit`](super::BlindBidCircuit) does.
+    pub fn verify(&self, leaf: &BlsScalar) -> bool {
+        let key = SparseBidTree::<DEPTH>::key(leaf);
+        let mut needle = *leaf;
+        for (depth, level) in self.levels.iter().enumerate() {
+            let child = (key >> (2 * depth)) as usize % ARITY;
+            if level[child + 1] != needle {
+                return false;
+            }
+            needle = hash_level(level);
+        }
+        needle == self.root
+    }
+}
+
+/// Hashes a node of a Bid tree from its presence mask followed by its
+/// children, as the annotations of the dense Poseidon trees do.
+pub(crate) fn hash_level(level: &[BlsScalar]) -> BlsScalar {
+    let mut perm = [BlsScalar::zero(); WIDTH];
+    perm.copy_from_slice(level);
+    ScalarStrategy::new().perm(&mut perm);
+    perm[1]
+}
+
+/// Mirrors [`SparseBranch::verify`] inside of the Constraint System,
+/// returning the Variable that holds the computed root.
+///
+/// The `leaf` is decomposed into the `2 · DEPTH` bits of its key and the
+/// rest of its value, and every level places the needle at the child its
+/// two bits select. The rest of the value is only range checked to 256 bits,
+/// so the decomposition of `leaf + p` is accepted as well. That doesn't help
+/// a prover: the opening must still reach the root, and a [`SparseBidTree`]
+/// only stores a leaf at the key of its canonical value.
+pub(crate) fn sparse_opening_gadget<const DEPTH: usize>(
+    composer: &mut StandardComposer,
+    branch: &SparseBranch<DEPTH>,
+    leaf: Variable,
+    leaf_value: BlsScalar,
+) -> Variable {
+    let one = BlsScalar::one();
+    let zero = composer.add_witness_to_circuit_description(BlsScalar::zero());
+
+    // Decompose the leaf into the bits of its key and the rest of it.
+    let bytes = leaf_value.to_bytes();
+    let mut weight = one;
+    let mut key_value = BlsScalar::zero();
+    let mut bits = Vec::with_capacity(2 * DEPTH);
+    let key = (0..2 * DEPTH).fold(zero, |key, i| {
+        let bit = BlsScalar::from(((bytes[i / 8] >> (i % 8)) & 1) as u64);
+        key_value += weight * bit;
+        let bit = composer.add_input(bit);
+        composer.boolean_gate(bit);
+        bits.push(bit);
+        let key = composer.add(
+            (one, key),
+            (weight, bit),
+            BlsScalar::zero(),
+            BlsScalar::zero(),
+        );
+        weight = weight.double();
+        key
+    });
+    // `weight` is `2^(2 · DEPTH)` at this point, which is never zero.
+    let rest =
+        composer.add_input((leaf_value - key_value) * weight.invert().unwrap());
+    composer.range_gate(rest, 256 - 2 * DEPTH);
+    let recomposed = composer.add(
+        (one, key),
+        (weight, rest),
+        BlsScalar::zero(),
+        BlsScalar::zero(),
+    );
+    composer.assert_equal(recomposed, leaf);
+
+    branch
+        .levels
+        .iter()
+        .enumerate()
+        .fold(leaf, |needle, (depth, level)| {
+            // Selectors of the child `b0 + 2 · b1` the needle is placed at.
+            let (b0, b1) = (bits[2 * depth], bits[2 * depth + 1]);
+            let s3 =
+                composer.mul(one, b0, b1, BlsScalar::zero(), BlsScalar::zero());
+            let s1 = composer.add(
+                (one, b0),
+                (-one, s3),
+                BlsScalar::zero(),
+                BlsScalar::zero(),
+            );
+            let s2 = composer.add(
+                (one, b1),
+                (-one, s3),
+                BlsScalar::zero(),
+                BlsScalar::zero(),
+            );
+            let s0 =
+                composer.add((-one, b0), (-one, b1), one, BlsScalar::zero());
+            let s0 = composer.add(
+                (one, s0),
+                (one, s3),
+                BlsScalar::zero(),
+                BlsScalar::zero(),
+            );
+
+            let mut perm = [zero; WIDTH];
+            perm[0] = composer.add_input(level[0]);
+            [s0, s1, s2, s3]
+                .iter()
+                .enumerate()
+                .for_each(|(i, selector)| {
+                    let sibling = composer.add_input(level[i + 1]);
+                    perm[i + 1] =
+                        composer.conditional_select(*selector, needle, sibling);
+                });
+            GadgetStrategy::new(composer).perm(&mut perm);
+            perm[1]
+        })
+}
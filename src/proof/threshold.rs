@@ -0,0 +1,215 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Circuit used to prove that the value committed inside of a
+//! [Bid](crate::Bid) is greater or equal than a public threshold without
+//! revealing it.
+
+use crate::bid::{blinder_generator, value_generator};
+use anyhow::Result;
+use dusk_bls12_381::BlsScalar;
+use dusk_jubjub::JubJubAffine;
+use dusk_plonk::constraint_system::ecc::scalar_mul::fixed_base::scalar_mul;
+use dusk_plonk::prelude::*;
+use plonk_gadgets::AllocatedScalar;
+
+/// The ThresholdCircuit is used to prove or verify that the value hidden in
+/// a Bid commitment is greater or equal than a public threshold `T`.
+///
+/// This is a much lighter proof than the one generated with the
+/// [`BlindBidCircuit`](super::BlindBidCircuit), meant for use cases such as
+/// tiered eligibility where only the minimum stake needs to be known.
+///
+/// The [Public Inputs](dusk_plonk::circuit_builder::PublicInput) **in order**
+/// used for the proof are:
+/// 1. Bid commitment field.
+/// 2. Threshold `T`.
+///
+/// # Example
+/// ```ignore
+/// let decrypted = bid.decrypt_data(&secret)?;
+/// let mut circuit = ThresholdCircuit {
+///     value: decrypted.value(),
+///     blinder: decrypted.blinder(),
+///     commitment: bid.commitment(),
+///     threshold: 100_000u64,
+///     trim_size: 1 << 13,
+///     pi_positions: vec![],
+/// };
+/// let (pk, vk) = circuit.compile(&pub_params)?;
+/// let proof = circuit.gen_proof(&pub_params, &pk, b"Threshold")?;
+///
+/// let pi = vec![
+///     PublicInput::AffinePoint(bid.commitment(), 0, 0),
+///     PublicInput::BlsScalar(BlsScalar::from(100_000u64), 0),
+/// ];
+/// circuit.verify_proof(&pub_params, &vk, b"Threshold", &proof, &pi)
+/// ```
+#[cfg_attr(docsrs, doc(cfg(all(feature = "proof", feature = "canon"))))]
+#[derive(Debug, Clone)]
+pub struct ThresholdCircuit {
+    /// Value committed inside of the Bid.
+    pub value: JubJubScalar,
+    /// Blinder used to generate the Bid commitment.
+    pub blinder: JubJubScalar,
+    /// Commitment of the Bid.
+    pub commitment: JubJubAffine,
+    /// Public minimum that the value has to reach.
+    pub threshold: u64,
+    /// Trim size of the Public Parameters used by the PLONK mechanism.
+    pub trim_size: usize,
+    /// Positions of the Public Inputs used with the proof.
+    pub pi_positions: Vec<PublicInput>,
+}
+
+impl<'a> Circuit<'a> for ThresholdCircuit {
+    fn gadget(&mut self, composer: &mut StandardComposer) -> Result<()> {
+        let commitment = self.commitment;
+        let threshold = BlsScalar::from(self.threshold);
+        let value = AllocatedScalar::allocate(composer, self.value.into());
+        let blinder = AllocatedScalar::allocate(composer, self.blinder.into());
+        let pi = self.get_mut_pi_positions();
+
+        // 1. c = C(v, b) Pedersen Commitment check
+        let p1 = scalar_mul(composer, value.var, value_generator());
+        let p2 = scalar_mul(composer, blinder.var, blinder_generator());
+        let computed_c = p1.point().fast_add(composer, *p2.point());
+        // Add PI constraint for the commitment computation check.
+        pi.push(PublicInput::AffinePoint(
+            commitment,
+            composer.circuit_size(),
+            composer.circuit_size() + 1,
+        ));
+        composer.assert_equal_public_point(computed_c, commitment);
+
+        // 2. v < 2^64
+        composer.range_gate(value.var, 64usize);
+
+        // 3. v >= T
+        // Constraint the threshold to the public one.
+        let threshold_var = composer.add_input(threshold);
+        pi.push(PublicInput::BlsScalar(-threshold, composer.circuit_size()));
+        composer.constrain_to_constant(
+            threshold_var,
+            BlsScalar::zero(),
+            -threshold,
+        );
+        // If v < T, the subtraction wraps around the field and the result
+        // can't fit in 64 bits.
+        let v_min_t = composer.add(
+            (BlsScalar::one(), value.var),
+            (-BlsScalar::one(), threshold_var),
+            BlsScalar::zero(),
+            BlsScalar::zero(),
+        );
+        composer.range_gate(v_min_t, 64usize);
+        Ok(())
+    }
+
+    fn get_pi_positions(&self) -> &Vec<PublicInput> {
+        &self.pi_positions
+    }
+
+    fn get_mut_pi_positions(&mut self) -> &mut Vec<PublicInput> {
+        &mut self.pi_positions
+    }
+
+    fn get_trim_size(&self) -> usize {
+        self.trim_size
+    }
+
+    fn set_trim_size(&mut self, size: usize) {
+        self.trim_size = size;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bid::Bid;
+    use crate::V_RAW_MIN;
+    use dusk_jubjub::GENERATOR_EXTENDED;
+    use dusk_pki::{PublicSpendKey, SecretSpendKey};
+
+    const LABEL: &[u8] = b"Threshold";
+
+    fn bid_and_secret() -> (Bid, JubJubAffine) {
+        let mut rng = rand::thread_rng();
+        let pk_r = PublicSpendKey::from(SecretSpendKey::random(&mut rng));
+        let secret = JubJubScalar::random(&mut rng);
+        let stealth_addr = pk_r.gen_stealth_address(&secret);
+        let secret: JubJubAffine = (GENERATOR_EXTENDED * secret).into();
+        let value = JubJubScalar::from(V_RAW_MIN + 1000);
+
+        let bid = Bid::new(
+            &mut rng,
+            &stealth_addr,
+            &value,
+            &secret,
+            BlsScalar::one(),
+            u64::MAX,
+            u64::MAX,
+        )
+        .expect("Bid creation error");
+        (bid, secret)
+    }
+
+    fn prove_and_verify(
+        threshold: u64,
+        tampered_commitment: Option<JubJubAffine>,
+    ) -> Result<()> {
+        let pub_params =
+            PublicParameters::setup(1 << 14, &mut rand::thread_rng())?;
+        let (bid, secret) = bid_and_secret();
+        let decrypted = bid.decrypt_data(&secret).expect("Decryption error");
+
+        let mut circuit = ThresholdCircuit {
+            value: decrypted.value(),
+            blinder: decrypted.blinder(),
+            commitment: bid.commitment(),
+            threshold,
+            trim_size: 1 << 13,
+            pi_positions: vec![],
+        };
+        let (pk, vk) = circuit.compile(&pub_params)?;
+        let proof = circuit.gen_proof(&pub_params, &pk, LABEL)?;
+
+        let pi = vec![
+            PublicInput::AffinePoint(
+                tampered_commitment.unwrap_or(bid.commitment()),
+                0,
+                0,
+            ),
+            PublicInput::BlsScalar(BlsScalar::from(threshold), 0),
+        ];
+        let mut circuit = ThresholdCircuit {
+            value: JubJubScalar::zero(),
+            blinder: JubJubScalar::zero(),
+            commitment: bid.commitment(),
+            threshold,
+            trim_size: 1 << 13,
+            pi_positions: vec![],
+        };
+        circuit.verify_proof(&pub_params, &vk, LABEL, &proof, &pi)
+    }
+
+    #[test]
+    fn value_at_threshold() -> Result<()> {
+        prove_and_verify(V_RAW_MIN + 1000, None)
+    }
+
+    #[test]
+    fn value_below_threshold() {
+        assert!(prove_and_verify(V_RAW_MIN + 1001, None).is_err());
+    }
+
+    #[test]
+    fn tampered_commitment() {
+        let tampered: JubJubAffine =
+            (value_generator() * JubJubScalar::from(V_RAW_MIN + 1000)).into();
+        assert!(prove_and_verify(V_RAW_MIN, Some(tampered)).is_err());
+    }
+}
@@ -6,6 +6,9 @@
 
 #![allow(non_snake_case)]
 
+use super::sparse::hash_level;
+use super::BID_TREE_DEPTH;
+use crate::errors::BlindBidError;
 use crate::Bid;
 use canonical::{Canon, Store};
 use canonical_derive::Canon;
@@ -15,6 +18,15 @@ use dusk_poseidon::tree::{
     PoseidonBranch, PoseidonLeaf, PoseidonMaxAnnotation, PoseidonTree,
 };
 
+/// The BidLeaf is the leaf type of the Poseidon trees that hold the Bids.
+///
+/// Every leaf stores the full [`Bid`], while the tree only hashes its
+/// [`Bid::hash`] through [`PoseidonLeaf::poseidon_hash`]. Therefore, the
+/// branches of the tree authenticate the hash of the Bid, which is what the
+/// [BlindBidCircuit](crate::BlindBidCircuit) opens, and the `pos` of the
+/// leaf is the one of the Bid. [`BidTree::push_bid`] ties both together by
+/// setting the `pos` of the Bid to the index it's appended at.
+#[cfg_attr(docsrs, doc(cfg(all(feature = "proof", feature = "canon"))))]
 #[derive(Debug, Clone, Copy, Canon)]
 pub struct BidLeaf(pub(crate) Bid);
 
@@ -72,37 +84,182 @@ where
     }
 }
 
-pub struct BidTree<S: Store>(
-    PoseidonTree<BidLeaf, PoseidonMaxAnnotation, S, 17usize>,
-);
+impl Bid {
+    /// Checks natively that `branch` opens the hash of the Bid at its `pos`
+    /// up to `root`.
+    ///
+    /// This is the same opening that the
+    /// [BlindBidCircuit](crate::BlindBidCircuit) proves, so it is a cheap
+    /// pre-check to discard bogus branches before verifying a proof. Unlike
+    /// the circuit, it also requires the position of the leaf to be the
+    /// `pos` of the Bid, as it is in the dense trees of [`BidLeaf`]s.
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "proof", feature = "canon"))))]
+    pub fn verify_branch<const DEPTH: usize>(
+        &self,
+        branch: &PoseidonBranch<DEPTH>,
+        root: &BlsScalar,
+    ) -> bool {
+        let path = branch.as_ref();
+        if path.is_empty() || **branch != self.hash() || branch.root() != root {
+            return false;
+        }
+
+        // Every level has to contain the hash of the level below at its
+        // offset, which also encodes the position of the leaf.
+        let mut pos = 0u64;
+        let mut weight = 1u64;
+        for levels in path.windows(2) {
+            if hash_level(levels[0].as_ref()) != *levels[1] {
+                return false;
+            }
+            let arity = levels[0].as_ref().len() as u64 - 1;
+            pos += (levels[0].offset() as u64 - 1) * weight;
+            weight = weight.saturating_mul(arity);
+        }
+        pos == self.pos()
+    }
+}
+
+/// Number of leaves that a [`BidTree`] of depth [`BID_TREE_DEPTH`] and
+/// arity 4 can hold.
+#[cfg_attr(docsrs, doc(cfg(all(feature = "proof", feature = "canon"))))]
+pub const BID_TREE_CAPACITY: u64 = 1 << (2 * BID_TREE_DEPTH);
+
+/// The BidTree is a dense Poseidon tree of [`BidLeaf`]s of depth
+/// [`BID_TREE_DEPTH`] whose branches can be proven with the
+/// [BlindBidCircuit](crate::BlindBidCircuit).
+///
+/// Every Bid is appended at the next free index, which becomes its `pos`,
+/// and the tree rejects the Bids that exceed its capacity with
+/// [`BlindBidError::TreeFull`]. Failures of the underlying store are
+/// reported as [`BlindBidError::IOError`].
+#[cfg_attr(docsrs, doc(cfg(all(feature = "proof", feature = "canon"))))]
+#[derive(Debug, Clone)]
+pub struct BidTree<S: Store> {
+    tree: PoseidonTree<BidLeaf, PoseidonMaxAnnotation, S, BID_TREE_DEPTH>,
+    len: u64,
+    capacity: u64,
+}
+
+impl<S> Default for BidTree<S>
+where
+    S: Store,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 impl<S> BidTree<S>
 where
     S: Store,
 {
-    /// Constructor
+    /// Generates an empty tree that can hold up to [`BID_TREE_CAPACITY`]
+    /// Bids.
     pub fn new() -> Self {
-        Self(PoseidonTree::new())
+        Self::with_capacity(BID_TREE_CAPACITY)
+    }
+
+    /// Generates an empty tree that can't hold more than `capacity` Bids.
+    ///
+    /// The capacity is bounded by [`BID_TREE_CAPACITY`].
+    pub fn with_capacity(capacity: u64) -> Self {
+        Self {
+            tree: PoseidonTree::new(),
+            len: 0,
+            capacity: core::cmp::min(capacity, BID_TREE_CAPACITY),
+        }
+    }
+
+    /// Number of Bids stored in the tree.
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Returns `true` if the tree doesn't hold any Bid.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Maximum number of Bids the tree can hold.
+    pub fn capacity(&self) -> u64 {
+        self.capacity
     }
 
-    /// Get a bid from a provided index
-    #[allow(dead_code)]
-    pub fn get(&self, idx: u64) -> Option<BidLeaf> {
-        self.0.get(idx as usize).unwrap()
+    /// Root of the tree.
+    pub fn root(&self) -> Result<BlsScalar, BlindBidError> {
+        self.tree.root().map_err(|_| BlindBidError::IOError)
     }
 
-    /// Append a bid to the tree and return its index
+    /// Returns the leaf stored at `pos`, or `None` if there is none.
+    pub fn get(&self, pos: u64) -> Result<Option<BidLeaf>, BlindBidError> {
+        self.tree
+            .get(pos as usize)
+            .map_err(|_| BlindBidError::IOError)
+    }
+
+    /// Appends a leaf at the last available position and returns it. The
+    /// `pos` of the Bid of the leaf is set to that position.
+    pub fn push(&mut self, leaf: BidLeaf) -> Result<u64, BlindBidError> {
+        if self.len >= self.capacity {
+            return Err(BlindBidError::TreeFull {
+                capacity: self.capacity,
+                required: self.len.saturating_add(1),
+            });
+        }
+        let pos = self.tree.push(leaf).map_err(|_| BlindBidError::IOError)?;
+        self.len += 1;
+        Ok(pos as u64)
+    }
+
+    /// Appends a Bid to the tree, setting its `pos` to the index it's stored
+    /// at, and returns that position together with the branch needed to
+    /// prove it.
+    pub fn push_bid(
+        &mut self,
+        bid: Bid,
+    ) -> Result<(u64, PoseidonBranch<BID_TREE_DEPTH>), BlindBidError> {
+        let pos = self.push(bid.into())?;
+        let branch =
+            self.poseidon_branch(pos)?.ok_or(BlindBidError::IOError)?;
+        Ok((pos, branch))
+    }
+
+    /// Appends a batch of leaves to the tree.
     ///
-    /// The index will be the last available position
-    pub fn push(&mut self, bid: BidLeaf) -> usize {
-        self.0.push(bid).unwrap()
+    /// The insertion is all-or-nothing: if the batch doesn't fit in the tree,
+    /// [`BlindBidError::TreeFull`] is returned and the tree is left
+    /// untouched. If the store fails halfway, the leaves of the batch that
+    /// were already appended are removed.
+    pub fn extend(&mut self, leaves: &[BidLeaf]) -> Result<(), BlindBidError> {
+        let required = (leaves.len() as u64)
+            .checked_add(self.len)
+            .filter(|required| *required <= self.capacity)
+            .ok_or(BlindBidError::TreeFull {
+                capacity: self.capacity,
+                required: self.len.saturating_add(leaves.len() as u64),
+            })?;
+
+        for (appended, leaf) in leaves.iter().enumerate() {
+            if self.tree.push(*leaf).is_err() {
+                for _ in 0..appended {
+                    self.tree.pop().map_err(|_| BlindBidError::IOError)?;
+                }
+                return Err(BlindBidError::IOError);
+            }
+        }
+        self.len = required;
+        Ok(())
     }
 
-    /// Returns a poseidon branch pointing at the specific index
+    /// Returns the branch that opens the leaf stored at `pos`, or `None` if
+    /// there is none.
     pub fn poseidon_branch(
         &self,
-        idx: usize,
-    ) -> Option<PoseidonBranch<17usize>> {
-        self.0.branch(idx).unwrap()
+        pos: u64,
+    ) -> Result<Option<PoseidonBranch<BID_TREE_DEPTH>>, BlindBidError> {
+        self.tree
+            .branch(pos as usize)
+            .map_err(|_| BlindBidError::IOError)
     }
 }
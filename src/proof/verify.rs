@@ -0,0 +1,168 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Verification entrypoints for the proofs generated with the
+//! [`BlindBidCircuit`].
+
+use super::{
+    BidOpening, BidWitness, BlindBidCircuit, BlindBidPublicInputs,
+    BlindBidVerifierBundle, BID_TREE_DEPTH, TRIM_SIZE,
+};
+use crate::bid::Score;
+use crate::errors::BlindBidError;
+use core::borrow::Borrow;
+use dusk_bls12_381::BlsScalar;
+use dusk_bytes::DeserializableSlice;
+use dusk_plonk::prelude::*;
+use dusk_poseidon::tree::PoseidonBranch;
+
+/// Status returned by [`verify_blind_bid_bytes`] when the proof is
+/// valid.
+pub const VERIFY_OK: i32 = 0;
+/// Status returned by [`verify_blind_bid_bytes`] when the
+/// `PublicParameters` can't be deserialized.
+pub const VERIFY_ERR_PUB_PARAMS: i32 = -1;
+/// Status returned by [`verify_blind_bid_bytes`] when the `VerifierKey`
+/// can't be deserialized.
+pub const VERIFY_ERR_VERIFIER_KEY: i32 = -2;
+/// Status returned by [`verify_blind_bid_bytes`] when the `Proof` can't
+/// be deserialized.
+pub const VERIFY_ERR_PROOF: i32 = -3;
+/// Status returned by [`verify_blind_bid_bytes`] when the Public Inputs
+/// can't be deserialized.
+pub const VERIFY_ERR_PUBLIC_INPUTS: i32 = -4;
+/// Status returned by [`verify_blind_bid_bytes`] when the proof does not
+/// verify against the provided Public Inputs.
+pub const VERIFY_ERR_INVALID_PROOF: i32 = -5;
+
+/// Verifies a proof generated with the [`BlindBidCircuit`] against its
+/// Public Inputs.
+///
+/// The Public Inputs are laid out in the order expected by the circuit
+/// internally, so verifiers don't need to know it. Proofs that don't verify
+/// are reported as [`BlindBidError::ProofVerificationFailed`].
+///
+/// Only proofs of circuits with a public commitment verify: see
+/// [`BlindBidCircuit::with_private_commitment`] for the other mode.
+#[cfg_attr(docsrs, doc(cfg(all(feature = "proof", feature = "canon"))))]
+pub fn verify_blind_bid(
+    pub_params: &PublicParameters,
+    vk: &VerifierKey,
+    label: &'static [u8],
+    proof: &Proof,
+    pi: &BlindBidPublicInputs,
+) -> Result<(), BlindBidError> {
+    // The verifier does not need any of the private fields of the circuit,
+    // so we just fill them with placeholder values.
+    let branch = PoseidonBranch::<BID_TREE_DEPTH>::default();
+    let mut circuit = BlindBidCircuit {
+        bid: BidWitness::default(),
+        score: Score::default(),
+        secret_k: BlsScalar::one(),
+        seed: BlsScalar::zero(),
+        latest_consensus_round: BlsScalar::zero(),
+        latest_consensus_step: BlsScalar::zero(),
+        branch: BidOpening::Dense(&branch),
+        trim_size: TRIM_SIZE,
+        private_commitment: false,
+        pi_positions: vec![],
+    };
+
+    circuit
+        .verify_proof(pub_params, vk, label, proof, &pi.to_public_inputs())
+        .map_err(|_| BlindBidError::ProofVerificationFailed)
+}
+
+/// Verifies several proofs generated with the [`BlindBidCircuit`] and the
+/// same verifier key against their Public Inputs.
+///
+/// This is **not** batch verification: the version of PLONK in use does not
+/// allow to batch the pairing checks nor the multi-scalar multiplications of
+/// several proofs, so each proof is still checked on its own, one after the
+/// other, and verifying `n` proofs costs `n` pairing checks. What is
+/// shared is the setup that [`verify_blind_bid`] repeats for every proof: the
+/// Public Parameters are trimmed, the circuit is synthesized (to get the
+/// positions of the Public Inputs) and the PLONK verifier and the Public
+/// Inputs buffer are set up only once for the whole batch, through
+/// [`BlindBidVerifierBundle::verify_batch`].
+///
+/// On failure, [`BlindBidError::BatchVerificationFailed`] carries the index
+/// of the first proof that does not verify, while a failure of the gadget
+/// is reported as [`BlindBidError::CircuitSynthesisFailed`].
+///
+/// The proofs can be provided by value or by reference, so the same decoded
+/// proof can be checked against several candidate Public Inputs without
+/// cloning it.
+#[cfg_attr(docsrs, doc(cfg(all(feature = "proof", feature = "canon"))))]
+pub fn verify_blind_bids_batch<P: Borrow<Proof>>(
+    pub_params: &PublicParameters,
+    vk: &VerifierKey,
+    label: &'static [u8],
+    items: &[(P, BlindBidPublicInputs)],
+) -> Result<(), BlindBidError> {
+    let branch = PoseidonBranch::<BID_TREE_DEPTH>::default();
+    let mut circuit = BlindBidCircuit {
+        bid: BidWitness::default(),
+        score: Score::default(),
+        secret_k: BlsScalar::one(),
+        seed: BlsScalar::zero(),
+        latest_consensus_round: BlsScalar::zero(),
+        latest_consensus_step: BlsScalar::zero(),
+        branch: BidOpening::Dense(&branch),
+        trim_size: TRIM_SIZE,
+        private_commitment: false,
+        pi_positions: vec![],
+    };
+    circuit
+        .gadget(&mut StandardComposer::with_expected_size(TRIM_SIZE))
+        .map_err(|_| BlindBidError::CircuitSynthesisFailed)?;
+    let bundle = BlindBidVerifierBundle::from_circuit(
+        &circuit,
+        pub_params,
+        vk.clone(),
+        label,
+    )?;
+
+    bundle.verify_batch(items)
+}
+
+/// Byte-level version of [`verify_blind_bid`] meant to be used across
+/// FFI boundaries.
+///
+/// All of the arguments are deserialized from their byte representations
+/// and the result of the verification is returned as a status code:
+/// [`VERIFY_OK`] if the proof is valid, or one of the negative `VERIFY_ERR_*`
+/// codes otherwise.
+#[cfg_attr(docsrs, doc(cfg(all(feature = "proof", feature = "canon"))))]
+pub fn verify_blind_bid_bytes(
+    pub_params_bytes: &[u8],
+    vk_bytes: &[u8],
+    label: &'static [u8],
+    proof_bytes: &[u8],
+    pi_bytes: &[u8],
+) -> i32 {
+    let pub_params = match PublicParameters::from_bytes(pub_params_bytes) {
+        Ok(pub_params) => pub_params,
+        Err(_) => return VERIFY_ERR_PUB_PARAMS,
+    };
+    let vk = match VerifierKey::from_bytes(vk_bytes) {
+        Ok(vk) => vk,
+        Err(_) => return VERIFY_ERR_VERIFIER_KEY,
+    };
+    let proof = match Proof::from_bytes(proof_bytes) {
+        Ok(proof) => proof,
+        Err(_) => return VERIFY_ERR_PROOF,
+    };
+    let pi = match BlindBidPublicInputs::from_slice(pi_bytes) {
+        Ok(pi) => pi,
+        Err(_) => return VERIFY_ERR_PUBLIC_INPUTS,
+    };
+
+    match verify_blind_bid(&pub_params, &vk, label, &proof, &pi) {
+        Ok(()) => VERIFY_OK,
+        Err(_) => VERIFY_ERR_INVALID_PROOF,
+    }
+}
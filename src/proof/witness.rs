@@ -0,0 +1,137 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Proving representation of a [Bid](crate::Bid).
+
+use crate::bid::Bid;
+use dusk_bls12_381::BlsScalar;
+use dusk_jubjub::{JubJubAffine, JubJubScalar};
+use dusk_pki::Ownable;
+
+/// The BidWitness contains only the fields of a [Bid] that the
+/// [BlindBidCircuit](super::BlindBidCircuit) consumes, together with the
+/// value & blinder hidden inside of its commitment.
+///
+/// It allows provers to drop the full `Bid` (including the parts of the
+/// cipher that the circuit doesn't need) once the witness has been derived.
+///
+/// Verifiers don't need any of the witness values, so they can just use
+/// [`BidWitness::default`].
+#[cfg_attr(docsrs, doc(cfg(all(feature = "proof", feature = "canon"))))]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BidWitness {
+    /// Poseidon hash of the Bid.
+    pub(crate) hash: BlsScalar,
+    /// Cipher words hashed as part of the Bid pre-image.
+    pub(crate) cipher: [BlsScalar; 2],
+    /// `pk_r` of the stealth address of the Bid.
+    pub(crate) pk_r: JubJubAffine,
+    /// `R` of the stealth address of the Bid.
+    pub(crate) R: JubJubAffine,
+    /// Hashed secret of the Bid.
+    pub(crate) hashed_secret: BlsScalar,
+    /// Commitment of the Bid.
+    pub(crate) commitment: JubJubAffine,
+    /// Elegibility height of the Bid.
+    pub(crate) eligibility: u64,
+    /// Expiration height of the Bid.
+    pub(crate) expiration: u64,
+    /// Position of the Bid in the Tree where it is stored.
+    pub(crate) pos: u64,
+    /// Value hidden inside of the commitment.
+    pub(crate) value: JubJubScalar,
+    /// Blinder used to generate the commitment.
+    pub(crate) blinder: JubJubScalar,
+}
+
+impl BidWitness {
+    /// Derives the witness of a Bid using the secret that decrypts its
+    /// value & blinder.
+    ///
+    /// If the decryption fails, value & blinder are set to an
+    /// impossible-to-obtain value. On that way, the failure is only detected
+    /// by the proof verification, and provers won't get any info about if
+    /// this secret can or not decrypt the cipher.
+    pub fn new(bid: &Bid, secret: &JubJubAffine) -> Self {
+        let (value, blinder) = bid
+            .decrypt_data(secret)
+            .map(|decrypted| (decrypted.value(), decrypted.blinder()))
+            .unwrap_or((JubJubScalar::one(), JubJubScalar::one()));
+
+        BidWitness {
+            hash: bid.hash(),
+            cipher: [
+                bid.encrypted_data().cipher()[0],
+                bid.encrypted_data().cipher()[1],
+            ],
+            pk_r: bid.stealth_address().pk_r().as_ref().into(),
+            R: bid.stealth_address().R().into(),
+            hashed_secret: bid.hashed_secret(),
+            commitment: bid.commitment(),
+            eligibility: bid.eligibility(),
+            expiration: bid.expiration(),
+            pos: bid.pos(),
+            value,
+            blinder,
+        }
+    }
+
+    /// Returns the Poseidon hash of the Bid the witness was derived from.
+    pub fn hash(&self) -> BlsScalar {
+        self.hash
+    }
+
+    /// Returns the commitment of the Bid the witness was derived from.
+    pub fn commitment(&self) -> JubJubAffine {
+        self.commitment
+    }
+
+    /// Returns the hashed secret of the Bid the witness was derived from.
+    pub fn hashed_secret(&self) -> BlsScalar {
+        self.hashed_secret
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::V_RAW_MIN;
+    use dusk_jubjub::GENERATOR_EXTENDED;
+    use dusk_pki::{PublicSpendKey, SecretSpendKey};
+
+    #[test]
+    fn witness_from_bid() {
+        let mut rng = rand::thread_rng();
+        let pk_r = PublicSpendKey::from(SecretSpendKey::random(&mut rng));
+        let secret = JubJubScalar::random(&mut rng);
+        let stealth_addr = pk_r.gen_stealth_address(&secret);
+        let secret: JubJubAffine = (GENERATOR_EXTENDED * secret).into();
+        let bid = Bid::new(
+            &mut rng,
+            &stealth_addr,
+            &JubJubScalar::from(V_RAW_MIN),
+            &secret,
+            BlsScalar::one(),
+            u64::MAX,
+            u64::MAX,
+        )
+        .expect("Bid creation error");
+        let decrypted = bid.decrypt_data(&secret).expect("Decryption error");
+        let (value, blinder) = (decrypted.value(), decrypted.blinder());
+
+        let witness = BidWitness::new(&bid, &secret);
+        assert_eq!(witness.hash(), bid.hash());
+        assert_eq!(witness.commitment(), bid.commitment());
+        assert_eq!(witness.hashed_secret(), bid.hashed_secret());
+        assert_eq!(witness.value, value);
+        assert_eq!(witness.blinder, blinder);
+
+        // A wrong secret produces an unprovable witness instead of failing.
+        let witness = BidWitness::new(&bid, &JubJubAffine::default());
+        assert_eq!(witness.value, JubJubScalar::one());
+        assert_eq!(witness.blinder, JubJubScalar::one());
+    }
+}
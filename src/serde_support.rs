@@ -0,0 +1,220 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! `serde` support for the [Bid] and the [Score].
+//!
+//! Both are serialized through their [Serializable] byte representation: as
+//! a lowercase hex string in human-readable formats (such as JSON) and as
+//! raw bytes in binary formats (such as bincode).
+
+use crate::{Bid, Score};
+use core::fmt;
+use dusk_bytes::Serializable;
+use serde::de::{self, Error as _, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Formats a byte slice as a lowercase hex string without allocating.
+struct Hex<'a>(&'a [u8]);
+
+impl fmt::Display for Hex<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.iter().try_for_each(|byte| write!(f, "{:02x}", byte))
+    }
+}
+
+fn serialize_bytes<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if serializer.is_human_readable() {
+        serializer.collect_str(&Hex(bytes))
+    } else {
+        serializer.serialize_bytes(bytes)
+    }
+}
+
+/// Visitor that accepts exactly `N` raw bytes or a hex string encoding them.
+struct BytesVisitor<const N: usize>;
+
+impl<'de, const N: usize> Visitor<'de> for BytesVisitor<N> {
+    type Value = [u8; N];
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} bytes or a hex string of {} characters", N, 2 * N)
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        if v.len() != 2 * N {
+            return Err(E::invalid_length(v.len(), &self));
+        }
+
+        let nibble = |c: u8| match c {
+            b'0'..=b'9' => Ok(c - b'0'),
+            b'a'..=b'f' => Ok(c - b'a' + 10),
+            b'A'..=b'F' => Ok(c - b'A' + 10),
+            _ => Err(E::invalid_value(de::Unexpected::Str(v), &self)),
+        };
+
+        let mut bytes = [0u8; N];
+        for (byte, pair) in bytes.iter_mut().zip(v.as_bytes().chunks(2)) {
+            *byte = nibble(pair[0])? << 4 | nibble(pair[1])?;
+        }
+        Ok(bytes)
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        if v.len() != N {
+            return Err(E::invalid_length(v.len(), &self));
+        }
+
+        let mut bytes = [0u8; N];
+        bytes.copy_from_slice(v);
+        Ok(bytes)
+    }
+}
+
+fn deserialize_bytes<'de, D, const N: usize>(
+    deserializer: D,
+) -> Result<[u8; N], D::Error>
+where
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(BytesVisitor::<N>)
+    } else {
+        deserializer.deserialize_bytes(BytesVisitor::<N>)
+    }
+}
+
+impl Serialize for Bid {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_bytes(&self.to_bytes(), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Bid {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let bytes = deserialize_bytes::<_, { Bid::SIZE }>(deserializer)?;
+        Bid::from_bytes(&bytes).map_err(|e| {
+            D::Error::custom(format_args!("invalid Bid encoding: {:?}", e))
+        })
+    }
+}
+
+impl Serialize for Score {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_bytes(&self.to_bytes(), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Score {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let bytes = deserialize_bytes::<_, { Score::SIZE }>(deserializer)?;
+        Score::from_bytes(&bytes).map_err(|e| {
+            D::Error::custom(format_args!("invalid Score encoding: {:?}", e))
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::V_RAW_MIN;
+    use dusk_bls12_381::BlsScalar;
+    use dusk_jubjub::{JubJubAffine, JubJubScalar, GENERATOR_EXTENDED};
+    use dusk_pki::{PublicSpendKey, SecretSpendKey};
+
+    fn random_bid() -> Bid {
+        let mut rng = rand::thread_rng();
+        let pk_r = PublicSpendKey::from(SecretSpendKey::random(&mut rng));
+        let secret = JubJubScalar::random(&mut rng);
+        let stealth_addr = pk_r.gen_stealth_address(&secret);
+        let secret: JubJubAffine = (GENERATOR_EXTENDED * secret).into();
+
+        Bid::new(
+            &mut rng,
+            &stealth_addr,
+            &JubJubScalar::from(V_RAW_MIN),
+            &secret,
+            BlsScalar::random(&mut rng),
+            u64::MAX,
+            u64::MAX,
+        )
+        .expect("Bid creation error")
+    }
+
+    fn random_score() -> Score {
+        let mut rng = rand::thread_rng();
+        let mut bytes = [0u8; Score::SIZE];
+        bytes.chunks_mut(BlsScalar::SIZE).for_each(|chunk| {
+            chunk.copy_from_slice(&BlsScalar::random(&mut rng).to_bytes())
+        });
+        Score::from_bytes(&bytes).expect("Invalid Score encoding")
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let bid = random_bid();
+        let json = serde_json::to_string(&bid).expect("Serialization error");
+        assert_eq!(json, format!("\"{}\"", Hex(&bid.to_bytes())));
+        let bid_from_json: Bid =
+            serde_json::from_str(&json).expect("Deserialization error");
+        assert_eq!(bid.to_bytes()[..], bid_from_json.to_bytes()[..]);
+
+        let score = random_score();
+        let json = serde_json::to_string(&score).expect("Serialization error");
+        let score_from_json: Score =
+            serde_json::from_str(&json).expect("Deserialization error");
+        assert_eq!(score, score_from_json);
+    }
+
+    #[test]
+    fn bincode_roundtrip() {
+        let bid = random_bid();
+        let bin = bincode::serialize(&bid).expect("Serialization error");
+        let bid_from_bin: Bid =
+            bincode::deserialize(&bin).expect("Deserialization error");
+        assert_eq!(bid.to_bytes()[..], bid_from_bin.to_bytes()[..]);
+
+        let score = random_score();
+        let bin = bincode::serialize(&score).expect("Serialization error");
+        let score_from_bin: Score =
+            bincode::deserialize(&bin).expect("Deserialization error");
+        assert_eq!(score, score_from_bin);
+    }
+
+    #[test]
+    fn invalid_length_is_rejected() {
+        let bid = random_bid();
+        let json = format!("\"{}\"", Hex(&bid.to_bytes()[1..]));
+        let err = serde_json::from_str::<Bid>(&json)
+            .expect_err("Deserialization should fail");
+        assert!(err.to_string().contains("invalid length"));
+
+        let bin =
+            bincode::serialize(&Score::default()).expect("Serialization error");
+        assert!(bincode::deserialize::<Bid>(&bin).is_err());
+    }
+}